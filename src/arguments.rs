@@ -1,21 +1,78 @@
 use super::*;
 
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+  Json,
+  Table,
+}
+
 #[derive(Parser)]
 #[command(name = "readability")]
 #[command(about = "Extract readable content from HTML files", long_about = None)]
 pub(crate) struct Arguments {
+  #[arg(
+    long,
+    value_name = "PATH",
+    help = "Load options from a JSON or TOML file"
+  )]
+  config: Option<PathBuf>,
+  #[arg(
+    long,
+    help = "Print a diff of the document and metadata after each stage to stderr"
+  )]
+  diff: bool,
   #[arg(value_name = "FILE", help = "Path to the HTML file to parse")]
   input: PathBuf,
+  #[arg(
+    long,
+    value_name = "FORMAT",
+    help = "Print per-stage timing and element counts to stderr"
+  )]
+  report: Option<ReportFormat>,
 }
 
 impl Arguments {
   pub(crate) fn run(self) -> Result {
-    let html = fs::read_to_string(&self.input)?;
+    let bytes = fs::read(&self.input)?;
+
+    let options = match &self.config {
+      Some(path) => ReadabilityOptions::load(path)?,
+      None => ReadabilityOptions::default(),
+    };
+
+    let mut readability = Readability::from_bytes(
+      &bytes,
+      None,
+      None,
+      ReadabilityOptions {
+        snapshots: options.snapshots || self.diff,
+        ..options
+      },
+    )?;
+
+    let article = match self.report {
+      Some(format) => {
+        let (article, report) = readability.parse_with_report()?;
+
+        match format {
+          ReportFormat::Json => {
+            eprintln!("{}", serde_json::to_string_pretty(&report)?);
+          }
+          ReportFormat::Table => eprintln!("{report}"),
+        }
+
+        article
+      }
+      None => readability.parse()?,
+    };
 
-    let mut readability =
-      Readability::new(&html, None, ReadabilityOptions::default())?;
+    if self.diff {
+      for pair in readability.snapshots().windows(2) {
+        eprint!("{}", pair[1].diff(&pair[0]));
+      }
+    }
 
-    println!("{}", readability.parse()?.content);
+    println!("{}", article.content);
 
     Ok(())
   }
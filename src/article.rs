@@ -2,24 +2,47 @@ use super::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Article {
+  /// People credited with the article, with profile URLs when known.
+  #[serde(default)]
+  pub authors: Vec<Author>,
   /// Author name or attribution string when available.
   pub byline: Option<String>,
   /// HTML markup representing the extracted article content.
   pub content: String,
   /// Text direction hint sourced from the document.
   pub dir: Option<String>,
+  /// Character encoding the input was decoded from, when the parser was
+  /// created with `Readability::from_bytes`.
+  pub encoding: Option<String>,
   /// Summary or first paragraph of the article.
   pub excerpt: Option<String>,
+  /// URL of the lead image, suitable for a preview card. Relative URLs are
+  /// only resolved when a base URL is given.
+  pub image: Option<String>,
+  /// Keywords or tags describing the article.
+  #[serde(default)]
+  pub keywords: Vec<String>,
   /// Language hint discovered during parsing.
   pub lang: Option<String>,
   /// Character count of the plain-text content.
   pub length: usize,
+  /// Timestamp of the last modification, if present.
+  pub modified_time: Option<String>,
+  /// Publication timestamp parsed from `published_time`, or from a `<time>`
+  /// element near the byline. Dates without an offset are taken to be UTC.
+  /// The `chrono` types are re-exported as `readability::chrono`.
+  pub published_at: Option<DateTime<FixedOffset>>,
   /// Publication timestamp for the article if present.
   pub published_time: Option<String>,
+  /// Section of the publication the article belongs to.
+  pub section: Option<String>,
   /// Name of the website that published the article.
   pub site_name: Option<String>,
   /// Plain-text version of the extracted content.
   pub text_content: String,
   /// Human-readable title resolved for the article.
   pub title: String,
+  /// Canonical URL of the article when declared by the document. Relative URLs
+  /// are only resolved when a base URL is given.
+  pub url: Option<String>,
 }
@@ -0,0 +1,10 @@
+use super::*;
+
+/// A person credited with writing the article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Author {
+  /// Display name of the author.
+  pub name: String,
+  /// Profile or homepage URL of the author, when known.
+  pub url: Option<String>,
+}
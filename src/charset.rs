@@ -0,0 +1,135 @@
+use super::*;
+
+/// Number of leading bytes searched for a `<meta>` charset declaration, as in
+/// the HTML encoding prescan.
+const PRESCAN_LENGTH: usize = 1024;
+
+/// Decodes `bytes` to UTF-8 and returns the text along with the encoding used.
+///
+/// The encoding is taken from, in order of precedence, a byte order mark,
+/// `charset` (typically from a `Content-Type` header), or a `<meta charset>`
+/// or `http-equiv` declaration near the start of the document. Without any of
+/// these, the input is treated as UTF-8 when valid and windows-1252 otherwise.
+pub(crate) fn decode(
+  bytes: &[u8],
+  charset: Option<&str>,
+) -> (String, &'static Encoding) {
+  let encoding = Encoding::for_bom(bytes)
+    .map(|(encoding, _)| encoding)
+    .or_else(|| {
+      charset.and_then(|label| Encoding::for_label(label.trim().as_bytes()))
+    })
+    .or_else(|| prescan(bytes))
+    .unwrap_or_else(|| {
+      if str::from_utf8(bytes).is_ok() {
+        UTF_8
+      } else {
+        WINDOWS_1252
+      }
+    });
+
+  let (text, encoding, _) = encoding.decode(bytes);
+
+  (text.into_owned(), encoding)
+}
+
+fn prescan(bytes: &[u8]) -> Option<&'static Encoding> {
+  let head = String::from_utf8_lossy(&bytes[..bytes.len().min(PRESCAN_LENGTH)]);
+
+  META_CHARSET
+    .captures_iter(&head)
+    .find_map(|captures| Encoding::for_label(captures[1].as_bytes()))
+    .map(|encoding| {
+      // A document that could declare its own encoding in ASCII cannot be
+      // UTF-16, so such declarations are treated as UTF-8.
+      if encoding == UTF_16BE || encoding == UTF_16LE {
+        UTF_8
+      } else {
+        encoding
+      }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    encoding_rs::{GBK, SHIFT_JIS},
+  };
+
+  fn encode(encoding: &'static Encoding, html: &str) -> Vec<u8> {
+    encoding.encode(html).0.into_owned()
+  }
+
+  #[test]
+  fn bom_takes_precedence() {
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+
+    bytes
+      .extend_from_slice(r#"<meta charset="shift_jis"><p>café</p>"#.as_bytes());
+
+    let (text, encoding) = decode(&bytes, Some("windows-1252"));
+
+    assert_eq!(encoding, UTF_8);
+    assert!(text.contains("café"));
+  }
+
+  #[test]
+  fn header_charset_takes_precedence_over_meta() {
+    let bytes = encode(SHIFT_JIS, r#"<meta charset="utf-8"><p>日本語</p>"#);
+
+    let (text, encoding) = decode(&bytes, Some(" Shift_JIS "));
+
+    assert_eq!(encoding, SHIFT_JIS);
+    assert!(text.contains("日本語"));
+  }
+
+  #[test]
+  fn meta_charset() {
+    let bytes = encode(
+      GBK,
+      r"<html><head><meta charset='gbk'></head><body>中文</body></html>",
+    );
+
+    let (text, encoding) = decode(&bytes, None);
+
+    assert_eq!(encoding, GBK);
+    assert!(text.contains("中文"));
+  }
+
+  #[test]
+  fn meta_http_equiv() {
+    let bytes = encode(
+      SHIFT_JIS,
+      r#"<meta http-equiv="Content-Type" content="text/html; charset=Shift_JIS"><p>日本語</p>"#,
+    );
+
+    let (text, encoding) = decode(&bytes, None);
+
+    assert_eq!(encoding, SHIFT_JIS);
+    assert!(text.contains("日本語"));
+  }
+
+  #[test]
+  fn meta_utf_16_is_treated_as_utf_8() {
+    let (_, encoding) = decode(br#"<meta charset="utf-16">"#, None);
+
+    assert_eq!(encoding, UTF_8);
+  }
+
+  #[test]
+  fn unknown_labels_are_ignored() {
+    let (_, encoding) =
+      decode(br#"<meta charset="bogus"><p>plain</p>"#, Some("bogus"));
+
+    assert_eq!(encoding, UTF_8);
+  }
+
+  #[test]
+  fn undeclared_invalid_utf_8_falls_back_to_windows_1252() {
+    let (text, encoding) = decode(b"<p>caf\xE9</p>", None);
+
+    assert_eq!(encoding, WINDOWS_1252);
+    assert_eq!(text, "<p>café</p>");
+  }
+}
@@ -1,32 +1,104 @@
 use super::*;
 
-pub(crate) struct Context<'a> {
+/// State shared by the stages of a `Pipeline` while parsing one document.
+pub struct Context<'a> {
+  pub(crate) article: Option<NodeId>,
+  pub(crate) base_url: Option<&'a Url>,
+  pub(crate) data_tables: HashSet<NodeId>,
   pub(crate) dir: Option<String>,
   pub(crate) document: &'a mut dom_query::Document,
+  pub(crate) flags: Vec<Flag>,
   pub(crate) lang: Option<String>,
   pub(crate) metadata: Metadata,
   pub(crate) options: &'a ReadabilityOptions,
+  pub(crate) scores: HashMap<NodeId, f64>,
+  pub(crate) snapshots: Vec<Snapshot>,
+  pub(crate) stage: &'static str,
+  pub(crate) stage_reports: Option<Vec<StageReport>>,
+  pub(crate) top_candidate: Option<NodeId>,
 }
 
 impl<'a> Context<'a> {
+  /// The extracted article container, once `MergeSiblings` has run.
+  #[must_use]
+  pub fn article(&self) -> Option<NodeRef<'_>> {
+    self.article.and_then(|id| self.document.tree.get(&id))
+  }
+
+  /// The URL the document was loaded from, if known.
+  #[must_use]
+  pub fn base_url(&self) -> Option<&Url> {
+    self.base_url
+  }
+
   pub(crate) fn document(&mut self) -> Document<'_> {
     Document::new(&mut *self.document)
   }
 
+  /// The document being parsed. Nodes can be mutated through shared
+  /// references.
+  #[must_use]
+  pub fn dom(&self) -> &dom_query::Document {
+    self.document
+  }
+
+  pub(crate) fn has_flag(&self, flag: Flag) -> bool {
+    self.flags.contains(&flag)
+  }
+
+  pub(crate) fn link_density_bias(&self) -> f64 {
+    f64::from(self.options.link_density_bias)
+  }
+
   pub(crate) fn new(
     html: &'a mut dom_query::Document,
     options: &'a ReadabilityOptions,
   ) -> Self {
     Self {
+      article: None,
+      base_url: None,
+      data_tables: HashSet::new(),
       document: html,
       dir: None,
+      flags: Flag::ALL.to_vec(),
       lang: None,
       metadata: Metadata::default(),
       options,
+      scores: HashMap::new(),
+      snapshots: Vec::new(),
+      stage: "",
+      stage_reports: None,
+      top_candidate: None,
     }
   }
 
-  pub(crate) fn options(&self) -> &ReadabilityOptions {
+  /// The options parsing was started with.
+  #[must_use]
+  pub fn options(&self) -> &ReadabilityOptions {
     self.options
   }
+
+  /// Reports `kind` for `node` to `ReadabilityOptions::trace_sink` when
+  /// `ReadabilityOptions::debug` is set.
+  ///
+  /// Check `Context::tracing` first when building `kind` is costly, such as
+  /// when formatting a message.
+  pub fn trace(&self, node: Option<&NodeRef>, kind: TraceEventKind) {
+    if !self.tracing() {
+      return;
+    }
+
+    self.options.trace_sink.record(TraceEvent {
+      kind,
+      path: node.map(NodeRefExt::path),
+      stage: self.stage,
+    });
+  }
+
+  /// Whether `Context::trace` records events, that is, whether
+  /// `ReadabilityOptions::debug` is set.
+  #[must_use]
+  pub fn tracing(&self) -> bool {
+    self.options.debug
+  }
 }
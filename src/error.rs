@@ -1,5 +1,10 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+  #[error("failed to read config file `{}`: {source}", path.display())]
+  ConfigRead {
+    path: std::path::PathBuf,
+    source: std::io::Error,
+  },
   #[error("aborting parsing document; {found} elements found (limit: {limit})")]
   ElementLimitExceeded { found: usize, limit: usize },
   #[error("invalid base url: {source}")]
@@ -7,8 +12,32 @@ pub enum Error {
     #[from]
     source: url::ParseError,
   },
+  #[error("invalid JSON config: {source}")]
+  InvalidJsonConfig {
+    #[from]
+    source: serde_json::Error,
+  },
+  #[error("invalid {heuristic} pattern: {source}")]
+  InvalidPattern {
+    heuristic: crate::Heuristic,
+    source: regex::Error,
+  },
   #[error("invalid selector: {0}")]
   InvalidSelector(String),
+  #[error("invalid TOML config: {source}")]
+  InvalidTomlConfig {
+    #[from]
+    source: toml::de::Error,
+  },
   #[error("failed to identify article content")]
   MissingArticleContent,
+  #[error("stage {name} failed: {source}")]
+  Stage {
+    name: String,
+    source: Box<dyn std::error::Error + Send + Sync>,
+  },
+  #[error("unsupported config file format: `{}`", path.display())]
+  UnknownConfigFormat { path: std::path::PathBuf },
+  #[error("unknown stage: {name}")]
+  UnknownStage { name: String },
 }
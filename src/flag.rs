@@ -0,0 +1,20 @@
+/// Heuristics that can be relaxed when an extraction attempt yields too little
+/// text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Flag {
+  /// Remove low quality containers from the extracted article.
+  CleanConditionally,
+  /// Remove nodes whose `class`, `id`, or `role` suggest they are not content.
+  StripUnlikelys,
+  /// Adjust candidate scores by `class` and `id` weight.
+  WeightClasses,
+}
+
+impl Flag {
+  /// All flags, in the order they are disabled between attempts.
+  pub(crate) const ALL: [Flag; 3] = [
+    Flag::StripUnlikelys,
+    Flag::WeightClasses,
+    Flag::CleanConditionally,
+  ];
+}
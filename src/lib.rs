@@ -1,43 +1,86 @@
 use {
-  context::Context,
+  chrono::{
+    DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta,
+    Timelike,
+  },
   document::Document,
-  dom_query::{NodeRef, Selection},
-  metadata::Metadata,
-  pipeline::Pipeline,
+  dom_query::{NodeId, NodeRef, Selection},
+  encoding_rs::{Encoding, UTF_8, UTF_16BE, UTF_16LE, WINDOWS_1252},
+  flag::Flag,
+  node_ref_ext::NodeRefExt,
   re::{
-    BYLINE, MAYBE_CANDIDATE, META_PROPERTY, NUMERIC_HTML_ENTITY,
-    TITLE_HIERARCHICAL_SEPARATOR, TITLE_LEADING_JUNK,
-    TITLE_NORMALIZE_WHITESPACE, TITLE_SEPARATOR, UNLIKELY_CANDIDATE,
+    AD_WORDS, BYLINE, BYLINE_PARTICLE_PREFIX, BYLINE_PREFIX, BYLINE_ROLE,
+    BYLINE_SEPARATOR, BYLINE_TRAILER, HASH_URL, LOADING_WORDS, MAYBE_CANDIDATE,
+    META_CHARSET, META_PROPERTY, NEGATIVE, NORMALIZE_WHITESPACE,
+    NUMERIC_HTML_ENTITY, POSITIVE, SRCSET_URL, TIMEZONE,
+    TITLE_HIERARCHICAL_SEPARATOR, TITLE_LEADING_JUNK, TITLE_SEPARATOR,
+    UNLIKELY_CANDIDATE, VIDEOS,
   },
-  regex::Regex,
-  serde::{Deserialize, Serialize},
+  regex::{Captures, Regex},
+  serde::{Deserialize, Deserializer, Serialize, Serializer, de},
   stage::{
-    ElementLimit, ExtractByline, ExtractDir, ExtractExcerpt, ExtractJsonLd,
-    ExtractLang, ExtractMetaTags, ExtractTitle, RemoveDisallowedNodes,
-    RemoveEmptyContainers, RemoveHiddenNodes, RemoveUnlikelyCandidates,
-    RewriteFontTags, RewriteLineBreaks, Stage, UnescapeHtmlEntities,
-    UnwrapNoscriptImages,
+    CleanAttributes, CleanConditionally, ElementLimit, ExtractAuthors,
+    ExtractByline, ExtractDir, ExtractExcerpt, ExtractImage, ExtractJsonLd,
+    ExtractLang, ExtractMetaTags, ExtractTitle, MarkDataTables, MergeSiblings,
+    ParsePublishedTime, RemoveDisallowedNodes, RemoveEmptyContainers,
+    RemoveHiddenNodes, RemoveUnlikelyCandidates, ResolveRelativeUrls,
+    RewriteFontTags, RewriteLineBreaks, ScoreCandidates, UnescapeHtmlEntities,
+    UnwrapNoscriptImages, WrapPage,
+  },
+  std::{
+    any::type_name,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Display, Formatter},
+    fs, iter, mem,
+    ops::Index,
+    path::Path,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+    time::{Duration, Instant},
   },
-  std::{collections::HashMap, iter, mem, sync::LazyLock},
   url::Url,
 };
 
+pub use chrono;
+
 pub use crate::{
   article::Article,
+  author::Author,
+  context::Context,
   error::Error,
+  metadata::Metadata,
   options::{ReadabilityOptions, ReadabilityOptionsBuilder},
+  patterns::{Heuristic, Patterns},
+  pipeline::{Pipeline, PipelineBuilder},
   readability::Readability,
+  readerable::{
+    ReaderableOptions, ReaderableOptionsBuilder, is_probably_readerable,
+  },
+  report::{AttemptReport, ParseReport, StageReport},
+  snapshot::Snapshot,
+  stage::Stage,
+  trace::{StderrSink, TraceEvent, TraceEventKind, TraceSink, VecSink},
 };
 
 mod article;
+mod author;
+mod charset;
 mod context;
 mod document;
 mod error;
+mod flag;
 mod metadata;
+mod node_ref_ext;
 mod options;
+mod patterns;
 mod pipeline;
 mod re;
 mod readability;
+mod readerable;
+mod report;
+mod snapshot;
 mod stage;
+mod timestamp;
+mod trace;
 
 pub type Result<T = (), E = Error> = std::result::Result<T, E>;
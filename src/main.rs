@@ -1,6 +1,6 @@
 use {
   arguments::Arguments,
-  clap::Parser,
+  clap::{Parser, ValueEnum},
   readability::{Readability, ReadabilityOptions},
   std::{backtrace::BacktraceStatus, fs, path::PathBuf, process},
 };
@@ -1,8 +1,31 @@
+use super::*;
+
+/// Article metadata gathered from the document by the extraction stages.
 #[derive(Debug, Clone, Default, PartialEq)]
-pub(crate) struct Metadata {
-  pub(crate) byline: Option<String>,
-  pub(crate) excerpt: Option<String>,
-  pub(crate) published_time: Option<String>,
-  pub(crate) site_name: Option<String>,
-  pub(crate) title: Option<String>,
+pub struct Metadata {
+  /// People credited with the article.
+  pub authors: Vec<Author>,
+  /// Author name or attribution string.
+  pub byline: Option<String>,
+  /// Summary or first paragraph of the article.
+  pub excerpt: Option<String>,
+  /// URL of the lead image.
+  pub image: Option<String>,
+  /// Keywords or tags describing the article.
+  pub keywords: Vec<String>,
+  /// Timestamp of the last modification.
+  pub modified_time: Option<String>,
+  /// `published_time`, or failing that a `<time>` element near the byline,
+  /// parsed into a timezone-aware timestamp.
+  pub published_at: Option<DateTime<FixedOffset>>,
+  /// Publication timestamp.
+  pub published_time: Option<String>,
+  /// Section of the publication the article belongs to.
+  pub section: Option<String>,
+  /// Name of the website that published the article.
+  pub site_name: Option<String>,
+  /// Title of the article.
+  pub title: Option<String>,
+  /// Canonical URL of the article.
+  pub url: Option<String>,
 }
@@ -0,0 +1,402 @@
+use super::*;
+
+const PHRASING_ELEMENTS: &[&str] = &[
+  "abbr", "audio", "b", "bdo", "br", "button", "cite", "code", "data",
+  "datalist", "dfn", "em", "embed", "i", "img", "input", "kbd", "label",
+  "mark", "math", "meter", "noscript", "object", "output", "progress", "q",
+  "ruby", "samp", "script", "select", "small", "span", "strong", "sub", "sup",
+  "textarea", "time", "var", "wbr",
+];
+
+/// Fraction of a fragment-only link's text counted towards link density.
+const HASH_LINK_WEIGHT: f64 = 0.3;
+
+/// Heuristics over individual nodes shared by the scoring and cleaning stages.
+pub(crate) trait NodeRefExt<'a> {
+  /// Returns `-25`, `0`, or `25` per `class` and `id` depending on whether they
+  /// match the `Heuristic::Negative` or `Heuristic::Positive` patterns.
+  fn class_weight(&self, patterns: &Patterns) -> f64;
+
+  /// Whether the node or any of its descendants is an allowed video, as
+  /// decided by `is_allowed_video`.
+  fn contains_allowed_video(&self, allowed: &Regex) -> bool;
+
+  /// Returns all element descendants of the node in document order.
+  fn element_descendants(&self) -> Vec<NodeRef<'a>>;
+
+  /// Whether the node is inside a `<header>` or an element whose class and id
+  /// match `byline`, itself included.
+  fn in_byline_area(&self, byline: &Regex) -> bool;
+
+  /// Returns the trimmed text content with whitespace runs collapsed.
+  fn inner_text(&self) -> String;
+
+  /// Whether the node is an `<iframe>`, `<embed>`, or `<object>` with an
+  /// attribute, or for `<object>` its contents, matching `allowed`.
+  fn is_allowed_video(&self, allowed: &Regex) -> bool;
+
+  /// Whether the node is a text node or phrasing element.
+  fn is_phrasing_content(&self) -> bool;
+
+  /// Whether the node is a blank text node or a `<br>`.
+  fn is_whitespace(&self) -> bool;
+
+  /// Ratio of text inside `<a>` descendants to the node's total text, minus
+  /// `bias` and clamped to `[0, 1]`.
+  ///
+  /// Text of links whose `href` only points at a fragment counts for
+  /// `HASH_LINK_WEIGHT` of its length, since in-page navigation is less likely
+  /// to be boilerplate than links to other pages.
+  fn link_density(&self, bias: f64) -> f64;
+
+  /// Path from the root element to the node, with each element written as
+  /// `tag#id.class`, for example `html > body > div#main > p.intro`.
+  fn path(&self) -> String;
+
+  /// Upper-cased tag name, or an empty string for non-element nodes.
+  fn tag_name(&self) -> String;
+
+  /// Ratio of text inside descendants matching `tags` to the node's total
+  /// text.
+  fn text_density(&self, tags: &[&str]) -> f64;
+}
+
+impl<'a> NodeRefExt<'a> for NodeRef<'a> {
+  fn class_weight(&self, patterns: &Patterns) -> f64 {
+    let mut weight = 0.0;
+
+    for attribute in ["class", "id"] {
+      let Some(value) = self.attr(attribute).filter(|value| !value.is_empty())
+      else {
+        continue;
+      };
+
+      if patterns.negative.is_match(&value) {
+        weight -= 25.0;
+      }
+
+      if patterns.positive.is_match(&value) {
+        weight += 25.0;
+      }
+    }
+
+    weight
+  }
+
+  fn contains_allowed_video(&self, allowed: &Regex) -> bool {
+    self.is_allowed_video(allowed)
+      || Selection::from(self.clone())
+        .select("iframe, embed, object")
+        .nodes()
+        .iter()
+        .any(|embed| embed.is_allowed_video(allowed))
+  }
+
+  fn element_descendants(&self) -> Vec<NodeRef<'a>> {
+    Selection::from(self.clone()).select("*").nodes().to_vec()
+  }
+
+  fn in_byline_area(&self, byline: &Regex) -> bool {
+    iter::once(self.clone())
+      .chain(self.ancestors(None))
+      .any(|node| {
+        let class = node.attr("class").unwrap_or_default();
+        let id = node.attr("id").unwrap_or_default();
+
+        node.tag_name() == "HEADER" || byline.is_match(&format!("{class} {id}"))
+      })
+  }
+
+  fn inner_text(&self) -> String {
+    NORMALIZE_WHITESPACE
+      .replace_all(self.text().trim(), " ")
+      .into_owned()
+  }
+
+  fn is_allowed_video(&self, allowed: &Regex) -> bool {
+    self.is("iframe, embed, object")
+      && (self
+        .attrs()
+        .iter()
+        .any(|attribute| allowed.is_match(&attribute.value))
+        || (self.is("object") && allowed.is_match(&self.inner_html())))
+  }
+
+  fn is_phrasing_content(&self) -> bool {
+    if self.is_text() {
+      return true;
+    }
+
+    let Some(name) = self.node_name() else {
+      return false;
+    };
+
+    let name = name.to_ascii_lowercase();
+
+    PHRASING_ELEMENTS.contains(&name.as_ref())
+      || matches!(name.as_ref(), "a" | "del" | "ins")
+        && self.children().iter().all(NodeRefExt::is_phrasing_content)
+  }
+
+  fn is_whitespace(&self) -> bool {
+    (self.is_text() && self.text().trim().is_empty()) || self.is("br")
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  fn link_density(&self, bias: f64) -> f64 {
+    let text_length = self.inner_text().chars().count();
+
+    if text_length == 0 {
+      return 0.0;
+    }
+
+    let link_length = Selection::from(self.clone())
+      .select("a")
+      .nodes()
+      .iter()
+      .map(|link| {
+        let weight = if link
+          .attr("href")
+          .is_some_and(|href| HASH_URL.is_match(&href))
+        {
+          HASH_LINK_WEIGHT
+        } else {
+          1.0
+        };
+
+        link.inner_text().chars().count() as f64 * weight
+      })
+      .sum::<f64>();
+
+    (link_length / text_length as f64 - bias).clamp(0.0, 1.0)
+  }
+
+  fn path(&self) -> String {
+    let mut nodes = self.ancestors(None);
+
+    nodes.reverse();
+    nodes.push(self.clone());
+
+    nodes
+      .iter()
+      .filter(|node| node.is_element())
+      .map(|node| {
+        let mut segment = node.tag_name().to_lowercase();
+
+        if let Some(id) = node.attr("id").filter(|id| !id.is_empty()) {
+          segment.push('#');
+          segment.push_str(&id);
+        }
+
+        for class in node.attr("class").unwrap_or_default().split_whitespace() {
+          segment.push('.');
+          segment.push_str(class);
+        }
+
+        segment
+      })
+      .collect::<Vec<_>>()
+      .join(" > ")
+  }
+
+  fn tag_name(&self) -> String {
+    self
+      .node_name()
+      .map(|name| name.to_uppercase())
+      .unwrap_or_default()
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  fn text_density(&self, tags: &[&str]) -> f64 {
+    let text_length = self.inner_text().chars().count();
+
+    if text_length == 0 {
+      return 0.0;
+    }
+
+    let children_length = Selection::from(self.clone())
+      .select(&tags.join(","))
+      .nodes()
+      .iter()
+      .map(|child| child.inner_text().chars().count())
+      .sum::<usize>();
+
+    children_length as f64 / text_length as f64
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn with_node(html: &str, selector: &str, f: impl FnOnce(&NodeRef)) {
+    let document = dom_query::Document::from(html);
+    f(&document.select(selector).nodes()[0]);
+  }
+
+  #[test]
+  fn class_weight_negative() {
+    with_node(r#"<div class="sidebar">foo</div>"#, "div", |node| {
+      assert!(
+        (node.class_weight(&Patterns::default()) + 25.0).abs() < f64::EPSILON
+      );
+    });
+  }
+
+  #[test]
+  fn class_weight_custom_patterns() {
+    let mut patterns = Patterns::default();
+
+    patterns
+      .replace(Heuristic::Negative, "sidebar|footer")
+      .unwrap();
+
+    with_node(r#"<div class="comment">foo</div>"#, "div", |node| {
+      assert!(node.class_weight(&patterns).abs() < f64::EPSILON);
+    });
+  }
+
+  #[test]
+  fn class_weight_positive_class_and_id() {
+    with_node(
+      r#"<div class="article" id="content">foo</div>"#,
+      "div",
+      |node| {
+        assert!(
+          (node.class_weight(&Patterns::default()) - 50.0).abs() < f64::EPSILON
+        );
+      },
+    );
+  }
+
+  #[test]
+  fn element_descendants_stay_within_subtree() {
+    with_node(
+      "<div id=a><p>foo</p></div><div id=b><span>bar</span></div>",
+      "#a",
+      |node| {
+        assert_eq!(
+          node
+            .element_descendants()
+            .iter()
+            .map(NodeRefExt::tag_name)
+            .collect::<Vec<_>>(),
+          ["P"]
+        );
+      },
+    );
+  }
+
+  #[test]
+  fn contains_allowed_video() {
+    let allowed = Regex::new("//video\\.example").unwrap();
+
+    with_node(
+      r#"<div class="sidebar"><p><iframe src="https://video.example/1"></iframe></p></div>"#,
+      "div",
+      |node| assert!(node.contains_allowed_video(&allowed)),
+    );
+
+    with_node(
+      r#"<div><iframe src="https://ads.example/1"></iframe></div>"#,
+      "div",
+      |node| assert!(!node.contains_allowed_video(&allowed)),
+    );
+  }
+
+  #[test]
+  fn in_byline_area() {
+    let document = dom_query::Document::from(
+      r#"<header><a id="a">foo</a></header><p class="byline"><a id="b">bar</a></p><aside><a id="c">baz</a></aside>"#,
+    );
+
+    let in_byline_area = |selector: &str| {
+      document.select(selector).nodes()[0].in_byline_area(&BYLINE)
+    };
+
+    assert!(in_byline_area("#a"));
+    assert!(in_byline_area("#b"));
+    assert!(!in_byline_area("#c"));
+  }
+
+  #[test]
+  fn inner_text_collapses_whitespace() {
+    with_node("<p>  foo \n\n  bar  </p>", "p", |node| {
+      assert_eq!(node.inner_text(), "foo bar");
+    });
+  }
+
+  #[test]
+  fn is_allowed_video() {
+    let allowed = Regex::new("//video\\.example").unwrap();
+
+    with_node(
+      r#"<iframe src="https://video.example/1"></iframe>"#,
+      "iframe",
+      |node| {
+        assert!(node.is_allowed_video(&allowed));
+        assert!(!node.is_allowed_video(&VIDEOS));
+      },
+    );
+
+    with_node(
+      r#"<object><param value="https://video.example/1"></object>"#,
+      "object",
+      |node| assert!(node.is_allowed_video(&allowed)),
+    );
+
+    with_node(r#"<img src="https://video.example/1">"#, "img", |node| {
+      assert!(!node.is_allowed_video(&allowed));
+    });
+  }
+
+  #[test]
+  fn link_density_empty_text() {
+    with_node("<p></p>", "p", |node| {
+      assert!(node.link_density(0.0).abs() < f64::EPSILON);
+    });
+  }
+
+  #[test]
+  fn link_density_ratio() {
+    with_node(r#"<p>foo <a href="/x">bar</a></p>"#, "p", |node| {
+      assert!((node.link_density(0.0) - 3.0 / 7.0).abs() < f64::EPSILON);
+    });
+  }
+
+  #[test]
+  fn link_density_discounts_hash_links() {
+    with_node(r##"<p>foo <a href="#bar">bar</a></p>"##, "p", |node| {
+      assert!((node.link_density(0.0) - 0.9 / 7.0).abs() < f64::EPSILON);
+    });
+  }
+
+  #[test]
+  fn link_density_applies_bias() {
+    with_node(r#"<p>foo <a href="/x">bar</a></p>"#, "p", |node| {
+      assert!((node.link_density(0.1) - (3.0 / 7.0 - 0.1)).abs() < 1e-9);
+      assert!(node.link_density(1.0).abs() < f64::EPSILON);
+      assert!((node.link_density(-1.0) - 1.0).abs() < f64::EPSILON);
+    });
+  }
+
+  #[test]
+  fn path() {
+    with_node(
+      r#"<div id="main"><p class="intro lead">foo</p></div>"#,
+      "p",
+      |node| {
+        assert_eq!(node.path(), "html > body > div#main > p.intro.lead");
+      },
+    );
+  }
+
+  #[test]
+  fn text_density_ratio() {
+    with_node("<div>foo <span>bar</span><i>baz</i></div>", "div", |node| {
+      assert!((node.text_density(&["span"]) - 3.0 / 10.0).abs() < 1e-9);
+      assert!((node.text_density(&["span", "i"]) - 6.0 / 10.0).abs() < 1e-9);
+      assert!(node.text_density(&["p"]).abs() < f64::EPSILON);
+    });
+  }
+}
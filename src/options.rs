@@ -1,6 +1,24 @@
-#[derive(Debug, Clone)]
+use super::*;
+
+/// Options controlling how `Readability` extracts an article.
+///
+/// Options can be deserialized, for instance with `ReadabilityOptions::load`,
+/// in which case missing fields take their default values. Patterns are
+/// written as strings, and `trace_sink` cannot be configured this way.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct ReadabilityOptions {
-  /// Whether to enable logging of debug messages.
+  /// Embeds (`<iframe>`, `<embed>`, `<object>`) with an attribute matching
+  /// this pattern, and any element containing one, are kept by every
+  /// removal stage.
+  ///
+  /// Default: common video hosts such as `youtube.com` and `player.vimeo.com`
+  #[serde(
+    deserialize_with = "deserialize_regex",
+    serialize_with = "serialize_regex"
+  )]
+  pub allowed_video_regex: Regex,
+  /// Whether stages report what they do as `TraceEvent`s to `trace_sink`.
   ///
   /// Default: false
   pub debug: bool,
@@ -10,6 +28,10 @@ pub struct ReadabilityOptions {
   pub keep_classes: bool,
   /// A weighting applied to the link density calculation.
   ///
+  /// The bias is subtracted from every link density before it is compared
+  /// against a threshold, so positive values tolerate more links on pages such
+  /// as wikis and listicles, and negative values tolerate fewer.
+  ///
   /// Default: 0.0
   pub link_density_bias: f32,
   /// The maximum number of DOM elements to parse before aborting.
@@ -24,10 +46,29 @@ pub struct ReadabilityOptions {
   ///
   /// Default: 5
   pub n_top_candidates: usize,
+  /// Patterns driving the class name, id and title heuristics.
+  ///
+  /// Default: the built-in patterns
+  pub patterns: Patterns,
+  /// Attribute names that are never stripped from the article, even when they
+  /// are presentational.
+  ///
+  /// Default: \[\]
+  pub preserved_attributes: Vec<String>,
   /// A list of specific class names to preserve when `keep_classes` is false.
   ///
   /// Default: \["page"\]
   pub preserved_classes: Vec<String>,
+  /// Whether to record a `Snapshot` of the document and metadata after every
+  /// stage, available from `Readability::snapshots`.
+  ///
+  /// Default: false
+  pub snapshots: bool,
+  /// Where `TraceEvent`s are sent when `debug` is set.
+  ///
+  /// Default: `StderrSink`
+  #[serde(skip)]
+  pub trace_sink: Arc<dyn TraceSink>,
   /// Whether to extract metadata from JSON-LD.
   ///
   /// Default: true
@@ -37,13 +78,18 @@ pub struct ReadabilityOptions {
 impl Default for ReadabilityOptions {
   fn default() -> Self {
     Self {
+      allowed_video_regex: VIDEOS.clone(),
       debug: false,
       keep_classes: false,
       link_density_bias: 0.0,
       max_elements: None,
       min_text_length: 500,
       n_top_candidates: 5,
+      patterns: Patterns::default(),
+      preserved_attributes: Vec::new(),
       preserved_classes: vec!["page".to_string()],
+      snapshots: false,
+      trace_sink: Arc::new(StderrSink),
       use_json_ld: true,
     }
   }
@@ -54,6 +100,52 @@ impl ReadabilityOptions {
   pub fn builder() -> ReadabilityOptionsBuilder {
     ReadabilityOptionsBuilder::default()
   }
+
+  /// Parses options from a JSON object.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidJsonConfig` when `json` is malformed or contains
+  /// unknown or mistyped fields.
+  pub fn from_json(json: &str) -> Result<Self> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Parses options from a TOML document.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidTomlConfig` when `toml` is malformed or contains
+  /// unknown or mistyped fields.
+  pub fn from_toml(toml: &str) -> Result<Self> {
+    Ok(toml::from_str(toml)?)
+  }
+
+  /// Reads options from a `.json` or `.toml` file, chosen by extension.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ConfigRead` when the file cannot be read,
+  /// `Error::UnknownConfigFormat` for any other extension, or the errors of
+  /// `from_json` and `from_toml`.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+    let path = path.as_ref();
+
+    let read = || {
+      fs::read_to_string(path).map_err(|source| Error::ConfigRead {
+        path: path.to_owned(),
+        source,
+      })
+    };
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+      Some("json") => Self::from_json(&read()?),
+      Some("toml") => Self::from_toml(&read()?),
+      _ => Err(Error::UnknownConfigFormat {
+        path: path.to_owned(),
+      }),
+    }
+  }
 }
 
 #[derive(Default)]
@@ -62,6 +154,30 @@ pub struct ReadabilityOptionsBuilder {
 }
 
 impl ReadabilityOptionsBuilder {
+  #[must_use]
+  pub fn allowed_video_regex(self, allowed_video_regex: Regex) -> Self {
+    Self {
+      inner: ReadabilityOptions {
+        allowed_video_regex,
+        ..self.inner
+      },
+    }
+  }
+
+  #[must_use]
+  pub fn attributes_to_preserve<I, S>(self, attributes: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    Self {
+      inner: ReadabilityOptions {
+        preserved_attributes: attributes.into_iter().map(Into::into).collect(),
+        ..self.inner
+      },
+    }
+  }
+
   #[must_use]
   pub fn build(self) -> ReadabilityOptions {
     self.inner
@@ -91,6 +207,22 @@ impl ReadabilityOptionsBuilder {
     }
   }
 
+  /// Adds `pattern` as an alternative to the pattern used by `heuristic`.
+  /// Flags set at the start of the built-in pattern, such as `(?i)`, also
+  /// apply to `pattern`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidPattern` when `pattern` fails to compile.
+  pub fn extend_pattern(
+    mut self,
+    heuristic: Heuristic,
+    pattern: &str,
+  ) -> Result<Self> {
+    self.inner.patterns.extend(heuristic, pattern)?;
+    Ok(self)
+  }
+
   #[must_use]
   pub fn keep_classes(self, keep_classes: bool) -> Self {
     Self {
@@ -141,6 +273,40 @@ impl ReadabilityOptionsBuilder {
     }
   }
 
+  /// Replaces the pattern used by `heuristic` with `pattern`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidPattern` when `pattern` fails to compile.
+  pub fn replace_pattern(
+    mut self,
+    heuristic: Heuristic,
+    pattern: &str,
+  ) -> Result<Self> {
+    self.inner.patterns.replace(heuristic, pattern)?;
+    Ok(self)
+  }
+
+  #[must_use]
+  pub fn snapshots(self, snapshots: bool) -> Self {
+    Self {
+      inner: ReadabilityOptions {
+        snapshots,
+        ..self.inner
+      },
+    }
+  }
+
+  #[must_use]
+  pub fn trace_sink(self, trace_sink: impl TraceSink + 'static) -> Self {
+    Self {
+      inner: ReadabilityOptions {
+        trace_sink: Arc::new(trace_sink),
+        ..self.inner
+      },
+    }
+  }
+
   #[must_use]
   pub fn use_json_ld(self, use_json_ld: bool) -> Self {
     Self {
@@ -151,3 +317,95 @@ impl ReadabilityOptionsBuilder {
     }
   }
 }
+
+pub(crate) fn deserialize_regex<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> std::result::Result<Regex, D::Error> {
+  Regex::new(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+}
+
+pub(crate) fn serialize_regex<S: Serializer>(
+  regex: &Regex,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.serialize_str(regex.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn missing_fields_take_defaults() {
+    let options =
+      ReadabilityOptions::from_json(r#"{ "min_text_length": 42 }"#).unwrap();
+
+    assert_eq!(options.min_text_length, 42);
+    assert_eq!(options.n_top_candidates, 5);
+    assert_eq!(options.preserved_classes, vec!["page"]);
+    assert_eq!(options.allowed_video_regex.as_str(), VIDEOS.as_str());
+  }
+
+  #[test]
+  fn from_toml() {
+    let options = ReadabilityOptions::from_toml(
+      r#"
+      allowed_video_regex = "example\\.com"
+      keep_classes = true
+      link_density_bias = 0.25
+      max_elements = 1000
+      "#,
+    )
+    .unwrap();
+
+    assert!(options.keep_classes);
+    assert!((options.link_density_bias - 0.25).abs() < f32::EPSILON);
+    assert_eq!(options.max_elements, Some(1000));
+    assert!(options.allowed_video_regex.is_match("example.com"));
+    assert!(!options.allowed_video_regex.is_match("youtube.com"));
+  }
+
+  #[test]
+  fn round_trips_through_json() {
+    let options = ReadabilityOptions::builder()
+      .classes_to_preserve(["foo"])
+      .min_text_length(7)
+      .build();
+
+    let options =
+      ReadabilityOptions::from_json(&serde_json::to_string(&options).unwrap())
+        .unwrap();
+
+    assert_eq!(options.min_text_length, 7);
+    assert_eq!(options.preserved_classes, vec!["foo"]);
+  }
+
+  #[test]
+  fn rejects_unknown_fields() {
+    assert!(matches!(
+      ReadabilityOptions::from_json(r#"{ "min_text_lenght": 42 }"#),
+      Err(Error::InvalidJsonConfig { .. })
+    ));
+
+    assert!(matches!(
+      ReadabilityOptions::from_toml("min_text_lenght = 42"),
+      Err(Error::InvalidTomlConfig { .. })
+    ));
+  }
+
+  #[test]
+  fn rejects_invalid_regex() {
+    assert!(matches!(
+      ReadabilityOptions::from_json(r#"{ "allowed_video_regex": "(" }"#),
+      Err(Error::InvalidJsonConfig { .. })
+    ));
+  }
+
+  #[test]
+  fn load_rejects_unknown_extension() {
+    assert!(matches!(
+      ReadabilityOptions::load("options.yaml"),
+      Err(Error::UnknownConfigFormat { .. })
+    ));
+  }
+}
@@ -0,0 +1,220 @@
+use super::*;
+
+/// A heuristic driven by a pattern that can be replaced or extended with
+/// `ReadabilityOptionsBuilder::replace_pattern` and
+/// `ReadabilityOptionsBuilder::extend_pattern`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+  /// Matched against class names and ids to find the byline.
+  Byline,
+  /// Matched against class names and ids to spare an element that also
+  /// matches `UnlikelyCandidate`.
+  MaybeCandidate,
+  /// Matched against class names and ids to lower the score of elements, and
+  /// remove them from the article, such as comments and sidebars.
+  Negative,
+  /// Matched against class names and ids to raise the score of elements likely
+  /// to hold the article.
+  Positive,
+  /// Separators implying the title is a path, such as `/` or `»`.
+  TitleHierarchicalSeparator,
+  /// Separators between the article title and the site name, such as `|`.
+  TitleSeparator,
+  /// Matched against class names and ids to remove elements unlikely to be
+  /// part of the article, such as comments and sidebars.
+  UnlikelyCandidate,
+}
+
+impl Display for Heuristic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Byline => "byline",
+      Self::MaybeCandidate => "maybe_candidate",
+      Self::Negative => "negative",
+      Self::Positive => "positive",
+      Self::TitleHierarchicalSeparator => "title_hierarchical_separator",
+      Self::TitleSeparator => "title_separator",
+      Self::UnlikelyCandidate => "unlikely_candidate",
+    })
+  }
+}
+
+/// The patterns used by each `Heuristic`, defaulting to the built-in ones.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Patterns {
+  /// Pattern for `Heuristic::Byline`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub byline: Regex,
+  /// Pattern for `Heuristic::MaybeCandidate`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub maybe_candidate: Regex,
+  /// Pattern for `Heuristic::Negative`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub negative: Regex,
+  /// Pattern for `Heuristic::Positive`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub positive: Regex,
+  /// Pattern for `Heuristic::TitleHierarchicalSeparator`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub title_hierarchical_separator: Regex,
+  /// Pattern for `Heuristic::TitleSeparator`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub title_separator: Regex,
+  /// Pattern for `Heuristic::UnlikelyCandidate`.
+  #[serde(
+    deserialize_with = "options::deserialize_regex",
+    serialize_with = "options::serialize_regex"
+  )]
+  pub unlikely_candidate: Regex,
+}
+
+impl Default for Patterns {
+  fn default() -> Self {
+    Self {
+      byline: BYLINE.clone(),
+      maybe_candidate: MAYBE_CANDIDATE.clone(),
+      negative: NEGATIVE.clone(),
+      positive: POSITIVE.clone(),
+      title_hierarchical_separator: TITLE_HIERARCHICAL_SEPARATOR.clone(),
+      title_separator: TITLE_SEPARATOR.clone(),
+      unlikely_candidate: UNLIKELY_CANDIDATE.clone(),
+    }
+  }
+}
+
+impl Patterns {
+  fn compile(heuristic: Heuristic, pattern: &str) -> Result<Regex> {
+    Regex::new(pattern)
+      .map_err(|source| Error::InvalidPattern { heuristic, source })
+  }
+
+  /// Adds `pattern` as an alternative to the current pattern for
+  /// `heuristic`. Flags set at the start of the current pattern, such as
+  /// `(?i)`, also apply to `pattern`.
+  pub(crate) fn extend(
+    &mut self,
+    heuristic: Heuristic,
+    pattern: &str,
+  ) -> Result {
+    Self::compile(heuristic, pattern)?;
+
+    let extended = Self::compile(
+      heuristic,
+      &format!("{}|(?:{pattern})", self[heuristic].as_str()),
+    )?;
+
+    *self.get_mut(heuristic) = extended;
+
+    Ok(())
+  }
+
+  fn get_mut(&mut self, heuristic: Heuristic) -> &mut Regex {
+    match heuristic {
+      Heuristic::Byline => &mut self.byline,
+      Heuristic::MaybeCandidate => &mut self.maybe_candidate,
+      Heuristic::Negative => &mut self.negative,
+      Heuristic::Positive => &mut self.positive,
+      Heuristic::TitleHierarchicalSeparator => {
+        &mut self.title_hierarchical_separator
+      }
+      Heuristic::TitleSeparator => &mut self.title_separator,
+      Heuristic::UnlikelyCandidate => &mut self.unlikely_candidate,
+    }
+  }
+
+  /// Replaces the pattern for `heuristic` with `pattern`.
+  pub(crate) fn replace(
+    &mut self,
+    heuristic: Heuristic,
+    pattern: &str,
+  ) -> Result {
+    *self.get_mut(heuristic) = Self::compile(heuristic, pattern)?;
+
+    Ok(())
+  }
+}
+
+impl Index<Heuristic> for Patterns {
+  type Output = Regex;
+
+  fn index(&self, heuristic: Heuristic) -> &Regex {
+    match heuristic {
+      Heuristic::Byline => &self.byline,
+      Heuristic::MaybeCandidate => &self.maybe_candidate,
+      Heuristic::Negative => &self.negative,
+      Heuristic::Positive => &self.positive,
+      Heuristic::TitleHierarchicalSeparator => {
+        &self.title_hierarchical_separator
+      }
+      Heuristic::TitleSeparator => &self.title_separator,
+      Heuristic::UnlikelyCandidate => &self.unlikely_candidate,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn extend_keeps_builtin_alternatives_and_flags() {
+    let mut patterns = Patterns::default();
+
+    patterns.extend(Heuristic::Byline, "posted-by").unwrap();
+
+    assert!(patterns.byline.is_match("Author"));
+    assert!(patterns.byline.is_match("POSTED-BY"));
+  }
+
+  #[test]
+  fn replace_drops_builtin_alternatives() {
+    let mut patterns = Patterns::default();
+
+    patterns
+      .replace(Heuristic::UnlikelyCandidate, "sidebar|footer")
+      .unwrap();
+
+    assert!(!patterns.unlikely_candidate.is_match("comment"));
+    assert!(patterns.unlikely_candidate.is_match("sidebar"));
+  }
+
+  #[test]
+  fn invalid_pattern_names_heuristic() {
+    let error = Patterns::default()
+      .extend(Heuristic::TitleSeparator, "(")
+      .unwrap_err();
+
+    assert!(matches!(
+      error,
+      Error::InvalidPattern {
+        heuristic: Heuristic::TitleSeparator,
+        ..
+      }
+    ));
+
+    assert!(
+      error
+        .to_string()
+        .starts_with("invalid title_separator pattern:")
+    );
+  }
+}
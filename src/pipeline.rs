@@ -1,59 +1,254 @@
 use super::*;
 
-pub(crate) struct Pipeline<'a> {
-  context: Context<'a>,
-  stages: Vec<Box<dyn Stage + 'a>>,
+/// An ordered list of `Stage`s run over a document by `Readability::parse`.
+///
+/// `Pipeline::default()` holds the built-in stages. Use `Pipeline::builder()`
+/// to add, remove, or replace stages by name before passing the result to
+/// `Readability::with_pipeline`.
+pub struct Pipeline {
+  stages: Vec<Box<dyn Stage>>,
 }
 
-impl<'a> Pipeline<'a> {
-  fn add_stage(&mut self, stage: Box<dyn Stage + 'a>) {
-    self.stages.push(stage);
+impl Debug for Pipeline {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.stage_names()).finish()
   }
+}
 
-  pub(crate) fn new(context: Context<'a>) -> Self {
+impl Default for Pipeline {
+  fn default() -> Self {
     Self {
-      context,
-      stages: Vec::new(),
+      stages: vec![
+        Box::new(ElementLimit),
+        Box::new(UnwrapNoscriptImages),
+        Box::new(ExtractDir),
+        Box::new(ExtractLang),
+        Box::new(ExtractJsonLd),
+        Box::new(ExtractMetaTags),
+        Box::new(RemoveDisallowedNodes),
+        Box::new(RemoveHiddenNodes),
+        Box::new(RemoveUnlikelyCandidates),
+        Box::new(RemoveEmptyContainers),
+        Box::new(RewriteLineBreaks),
+        Box::new(RewriteFontTags),
+        Box::new(ExtractTitle),
+        Box::new(ExtractByline),
+        Box::new(ExtractAuthors),
+        Box::new(ParsePublishedTime),
+        Box::new(ScoreCandidates),
+        Box::new(MergeSiblings),
+        Box::new(MarkDataTables),
+        Box::new(CleanConditionally),
+        Box::new(WrapPage),
+        Box::new(ResolveRelativeUrls),
+        Box::new(ExtractImage),
+        Box::new(CleanAttributes),
+        Box::new(ExtractExcerpt),
+        Box::new(UnescapeHtmlEntities),
+      ],
     }
   }
+}
+
+impl Pipeline {
+  /// Returns a builder starting from the default stages.
+  #[must_use]
+  pub fn builder() -> PipelineBuilder {
+    PipelineBuilder::default()
+  }
+
+  fn position(&self, name: &str) -> Result<usize> {
+    self
+      .stages
+      .iter()
+      .position(|stage| stage.name() == name)
+      .ok_or_else(|| Error::UnknownStage {
+        name: name.to_owned(),
+      })
+  }
+
+  pub(crate) fn run<'a>(
+    &mut self,
+    mut context: Context<'a>,
+  ) -> Result<Context<'a>> {
+    let snapshots = context.options().snapshots;
+
+    if snapshots {
+      context.snapshots.push(Snapshot::capture(&context, None));
+    }
 
-  pub(crate) fn run(mut self) -> Result<Context<'a>> {
     for stage in &mut self.stages {
-      stage.run(&mut self.context)?;
+      context.stage = stage.name();
+
+      if context.stage_reports.is_some() {
+        let elements_before = context.document().element_count();
+
+        let start = Instant::now();
+
+        stage.run(&mut context)?;
+
+        let duration = start.elapsed();
+
+        let elements_after = context.document().element_count();
+
+        if let Some(reports) = &mut context.stage_reports {
+          reports.push(StageReport {
+            duration,
+            elements_after,
+            elements_before,
+            stage: context.stage,
+          });
+        }
+      } else {
+        stage.run(&mut context)?;
+      }
+
+      if snapshots {
+        context
+          .snapshots
+          .push(Snapshot::capture(&context, Some(context.stage)));
+      }
     }
 
-    Ok(self.context)
-  }
-
-  pub(crate) fn with_default_stages(
-    context: Context<'a>,
-    _base_url: Option<&'a Url>,
-  ) -> Self {
-    let mut pipeline = Self::new(context);
-
-    let stages: Vec<Box<dyn Stage>> = vec![
-      Box::new(ElementLimit),
-      Box::new(UnwrapNoscriptImages),
-      Box::new(ExtractDir),
-      Box::new(ExtractLang),
-      Box::new(ExtractJsonLd),
-      Box::new(ExtractMetaTags),
-      Box::new(RemoveDisallowedNodes),
-      Box::new(RemoveHiddenNodes),
-      Box::new(RemoveUnlikelyCandidates),
-      Box::new(RemoveEmptyContainers),
-      Box::new(RewriteLineBreaks),
-      Box::new(RewriteFontTags),
-      Box::new(ExtractTitle),
-      Box::new(ExtractByline),
-      Box::new(ExtractExcerpt),
-      Box::new(UnescapeHtmlEntities),
-    ];
-
-    for stage in stages {
-      pipeline.add_stage(stage);
+    Ok(context)
+  }
+
+  /// Names of the stages, in the order they run.
+  #[must_use]
+  pub fn stage_names(&self) -> Vec<&'static str> {
+    self.stages.iter().map(|stage| stage.name()).collect()
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct PipelineBuilder {
+  inner: Pipeline,
+}
+
+impl PipelineBuilder {
+  #[must_use]
+  pub fn build(self) -> Pipeline {
+    self.inner
+  }
+
+  /// Inserts `stage` right after the stage called `name`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::UnknownStage` when no stage is called `name`.
+  pub fn insert_after(
+    mut self,
+    name: &str,
+    stage: impl Stage + 'static,
+  ) -> Result<Self> {
+    let index = self.inner.position(name)?;
+    self.inner.stages.insert(index + 1, Box::new(stage));
+    Ok(self)
+  }
+
+  /// Inserts `stage` right before the stage called `name`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::UnknownStage` when no stage is called `name`.
+  pub fn insert_before(
+    mut self,
+    name: &str,
+    stage: impl Stage + 'static,
+  ) -> Result<Self> {
+    let index = self.inner.position(name)?;
+    self.inner.stages.insert(index, Box::new(stage));
+    Ok(self)
+  }
+
+  /// Removes the stage called `name`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::UnknownStage` when no stage is called `name`.
+  pub fn remove(mut self, name: &str) -> Result<Self> {
+    let index = self.inner.position(name)?;
+    self.inner.stages.remove(index);
+    Ok(self)
+  }
+
+  /// Replaces the stage called `name` with `stage`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::UnknownStage` when no stage is called `name`.
+  pub fn replace(
+    mut self,
+    name: &str,
+    stage: impl Stage + 'static,
+  ) -> Result<Self> {
+    let index = self.inner.position(name)?;
+    self.inner.stages[index] = Box::new(stage);
+    Ok(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Noop;
+
+  impl Stage for Noop {
+    fn run(&mut self, _context: &mut Context<'_>) -> Result {
+      Ok(())
+    }
+  }
+
+  struct Wrapper<T>(T);
+
+  impl<T> Stage for Wrapper<T> {
+    fn run(&mut self, _context: &mut Context<'_>) -> Result {
+      Ok(())
     }
+  }
+
+  fn names(builder: Result<PipelineBuilder>) -> Vec<&'static str> {
+    builder.unwrap().build().stage_names()
+  }
+
+  #[test]
+  fn insert_after() {
+    let names = names(Pipeline::builder().insert_after("ElementLimit", Noop));
+    assert_eq!(names[..2], ["ElementLimit", "Noop"]);
+  }
+
+  #[test]
+  fn insert_before() {
+    let names = names(Pipeline::builder().insert_before("ElementLimit", Noop));
+    assert_eq!(names[..2], ["Noop", "ElementLimit"]);
+  }
+
+  #[test]
+  fn generic_stage_name() {
+    assert_eq!(Wrapper(Noop).name(), "Wrapper");
+    assert_eq!(Wrapper(String::new()).name(), "Wrapper");
+  }
+
+  #[test]
+  fn remove() {
+    let names = names(Pipeline::builder().remove("ElementLimit"));
+    assert!(!names.contains(&"ElementLimit"));
+    assert_eq!(names.len(), Pipeline::default().stage_names().len() - 1);
+  }
+
+  #[test]
+  fn replace() {
+    let names = names(Pipeline::builder().replace("ElementLimit", Noop));
+    assert_eq!(names[0], "Noop");
+    assert!(!names.contains(&"ElementLimit"));
+  }
 
-    pipeline
+  #[test]
+  fn unknown_stage() {
+    assert!(matches!(
+      Pipeline::builder().remove("Foo"),
+      Err(Error::UnknownStage { name }) if name == "Foo"
+    ));
   }
 }
@@ -6,29 +6,77 @@ macro_rules! re {
   };
 }
 
+pub(crate) static AD_WORDS: LazyLock<Regex> = re!(
+  r"(?i)^(ad(vertising|vertisement)?|pub(licité)?|werb(ung)?|广告|Реклама|Anuncio)$"
+);
+
 pub(crate) static BYLINE: LazyLock<Regex> =
   re!(r"(?i)byline|author|dateline|writtenby|p-author");
 
+pub(crate) static BYLINE_PARTICLE_PREFIX: LazyLock<Regex> =
+  re!(r"(?i)^\s*(?:di|von|av|af|door)\s+");
+
+pub(crate) static BYLINE_PREFIX: LazyLock<Regex> = re!(
+  r"(?i)^\s*(?:(?:(?:written|posted|reported)\s+)?(?:by|par|por|przez)\b\s*:?|(?:di|von|av|af|door)\s*:|(?:author|autor|auteur|autore|auteurs|authors|autoren|autores|autori)\s*:)\s*"
+);
+
+pub(crate) static BYLINE_ROLE: LazyLock<Regex> = re!(
+  r"(?i)\b(?:staff|writer|editor|reporter|correspondent|contributor|columnist|photographer|redakteur(?:in)?|redaktion|rédact(?:eur|rice)|rédaction|redacci[oó]n|redattore|redactie|redação)\b"
+);
+
+pub(crate) static BYLINE_SEPARATOR: LazyLock<Regex> =
+  re!(r"(?i)\s*(?:[,;&]|\b(?:and|und|et|og|och)\b)\s*|\s+(?-i:e|y|en)\s+");
+
+pub(crate) static BYLINE_TRAILER: LazyLock<Regex> =
+  re!(r"(?is)(?:\s+(?:on|updated|published)\b|\s*[|·•\n]|\s+[-–—]\s).*$");
+
+pub(crate) static HASH_URL: LazyLock<Regex> = re!(r"^#.+");
+
+pub(crate) static LOADING_WORDS: LazyLock<Regex> =
+  re!(r"(?i)^((loading|正在加载|Загрузка|chargement|cargando)(…|\.\.\.)?)$");
+
 pub(crate) static MAYBE_CANDIDATE: LazyLock<Regex> =
   re!(r"(?i)and|article|body|column|content|main|mathjax|shadow");
 
+pub(crate) static META_CHARSET: LazyLock<Regex> =
+  re!(r#"(?i)<meta\s[^>]*?charset\s*=\s*["']?\s*([a-z0-9_.:\-]+)"#);
+
 pub(crate) static META_PROPERTY: LazyLock<Regex> = re!(
-  r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|published_time|title|site_name)\s*"
+  r"(?i)\s*(article|dc|dcterm|og|twitter)\s*:\s*(author|creator|description|modified_time|published_time|section|title|site_name|url)\s*"
 );
 
+pub(crate) static NEGATIVE: LazyLock<Regex> = re!(
+  r"(?i)-ad-|hidden|^hid$| hid$| hid |^hid |banner|combx|comment|com-|contact|footer|gdpr|masthead|media|meta|outbrain|promo|related|scroll|share|shoutbox|sidebar|skyscraper|sponsor|shopping|tags|widget"
+);
+
+pub(crate) static NORMALIZE_WHITESPACE: LazyLock<Regex> = re!(r"\s{2,}");
+
 pub(crate) static NUMERIC_HTML_ENTITY: LazyLock<Regex> =
   re!(r"(?i)&#(?:x([0-9a-f]+)|([0-9]+));");
 
+pub(crate) static POSITIVE: LazyLock<Regex> = re!(
+  r"(?i)article|body|content|entry|hentry|h-entry|main|page|pagination|post|text|blog|story"
+);
+
+pub(crate) static SRCSET_URL: LazyLock<Regex> =
+  re!(r"(\S+)(\s+[\d.]+[xw])?(\s*(?:,|$))");
+
+pub(crate) static TIMEZONE: LazyLock<Regex> = re!(
+  r"(?i)(?:^|[\s\d])(?<offset>(?<zone>[a-z]{1,4})|(?<sign>[+-])(?<hours>\d{2}):?(?<minutes>\d{2}))$"
+);
+
 pub(crate) static TITLE_HIERARCHICAL_SEPARATOR: LazyLock<Regex> =
   re!(r"\s[\\/>»]\s");
 
 pub(crate) static TITLE_LEADING_JUNK: LazyLock<Regex> =
   re!(r"(?i)^[^|\-–—\/>»]*[|\-–—\/>»]");
 
-pub(crate) static TITLE_NORMALIZE_WHITESPACE: LazyLock<Regex> = re!(r"\s{2,}");
-
 pub(crate) static TITLE_SEPARATOR: LazyLock<Regex> = re!(r"\s[|\-–—\/>»]\s");
 
 pub(crate) static UNLIKELY_CANDIDATE: LazyLock<Regex> = re!(
   r"(?i)-ad-|ai2html|banner|breadcrumbs|combx|comment|community|cover-wrap|disqus|extra|footer|gdpr|header|legends|menu|related|remark|replies|rss|shoutbox|sidebar|skyscraper|social|sponsor|supplemental|ad-break|agegate|pagination|pager|popup|yom-remote"
 );
+
+pub(crate) static VIDEOS: LazyLock<Regex> = re!(
+  r"(?i)//(www\.)?((dailymotion|youtube|youtube-nocookie|player\.vimeo|v\.qq|bilibili|live\.bilibili)\.com|(archive|upload\.wikimedia)\.org|player\.twitch\.tv)"
+);
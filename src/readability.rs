@@ -1,19 +1,160 @@
 use super::*;
 
+/// The outcome of running the pipeline once with a given set of flags.
+struct Attempt {
+  article: Article,
+  snapshots: Vec<Snapshot>,
+  stage_reports: Vec<StageReport>,
+  text_length: usize,
+}
+
 pub struct Readability {
   base_url: Option<Url>,
+  encoding: Option<&'static Encoding>,
   html: dom_query::Document,
   options: ReadabilityOptions,
+  pipeline: Pipeline,
+  snapshots: Vec<Snapshot>,
 }
 
 impl Readability {
-  /// Creates a new readability parser instance.
+  fn attempt(&mut self, flags: &[Flag], report: bool) -> Result<Attempt> {
+    let mut document = self.html.clone();
+
+    let mut context = Context::new(&mut document, &self.options);
+
+    context.base_url = self.base_url.as_ref();
+    context.flags = flags.to_vec();
+    context.stage_reports = report.then(Vec::new);
+
+    let context = self.pipeline.run(context)?;
+
+    let article = context
+      .article
+      .and_then(|id| context.document.tree.get(&id));
+
+    let content = article
+      .as_ref()
+      .map(|article| article.inner_html().to_string())
+      .unwrap_or_default();
+
+    let text_content = article
+      .as_ref()
+      .map(|article| article.text().to_string())
+      .unwrap_or_default();
+
+    let text_length = article
+      .as_ref()
+      .map_or(0, |article| article.inner_text().chars().count());
+
+    let Metadata {
+      authors,
+      byline,
+      excerpt,
+      image,
+      keywords,
+      modified_time,
+      published_at,
+      published_time,
+      section,
+      site_name,
+      title,
+      url,
+    } = context.metadata;
+
+    let article = Article {
+      authors,
+      byline,
+      content,
+      dir: context.dir,
+      encoding: self.encoding.map(|encoding| encoding.name().to_owned()),
+      excerpt,
+      image,
+      keywords,
+      lang: context.lang,
+      length: text_content.len(),
+      modified_time,
+      published_at,
+      published_time,
+      section,
+      site_name,
+      text_content,
+      title: title.unwrap_or_default(),
+      url,
+    };
+
+    Ok(Attempt {
+      article,
+      snapshots: context.snapshots,
+      stage_reports: context.stage_reports.unwrap_or_default(),
+      text_length,
+    })
+  }
+
+  /// The document as it was passed in, unaffected by parsing.
+  #[must_use]
+  pub fn document(&self) -> &dom_query::Document {
+    &self.html
+  }
+
+  fn finish(
+    &mut self,
+    mut attempts: Vec<Attempt>,
+    selected: usize,
+  ) -> (Article, ParseReport) {
+    let report = ParseReport {
+      attempts: attempts
+        .iter_mut()
+        .map(|attempt| AttemptReport {
+          stages: mem::take(&mut attempt.stage_reports),
+          text_length: attempt.text_length,
+        })
+        .collect(),
+      selected,
+    };
+
+    let attempt = attempts.swap_remove(selected);
+
+    self.snapshots = attempt.snapshots;
+
+    (attempt.article, report)
+  }
+
+  /// Creates a new readability parser instance from undecoded bytes.
+  ///
+  /// The encoding is detected from a byte order mark, `charset` (typically
+  /// the `charset` parameter of an HTTP `Content-Type` header), or a
+  /// `<meta charset>` or `http-equiv` declaration, in that order, falling
+  /// back to UTF-8 or windows-1252. It is reported in `Article::encoding`.
   ///
   /// # Errors
   ///
   /// Returns an error when the optional `base_url` cannot be parsed.
-  pub fn new(
-    html: &str,
+  pub fn from_bytes(
+    bytes: &[u8],
+    charset: Option<&str>,
+    base_url: Option<&str>,
+    options: ReadabilityOptions,
+  ) -> Result<Self> {
+    let (html, encoding) = charset::decode(bytes, charset);
+
+    Ok(Self {
+      encoding: Some(encoding),
+      ..Self::new(&html, base_url, options)?
+    })
+  }
+
+  /// Creates a new readability parser instance from an already parsed
+  /// `document`, avoiding a round trip through HTML.
+  ///
+  /// Every call to `parse` runs on a copy of `document`, which stays intact
+  /// and is available through `Readability::document`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the optional `base_url` cannot be parsed.
+  pub fn from_document(
+    document: dom_query::Document,
     base_url: Option<&str>,
     options: ReadabilityOptions,
   ) -> Result<Self> {
@@ -23,42 +164,601 @@ impl Readability {
 
     Ok(Self {
       base_url,
-      html: dom_query::Document::from(html),
+      encoding: None,
+      html: document,
       options,
+      pipeline: Pipeline::default(),
+      snapshots: Vec::new(),
     })
   }
 
+  /// Like `from_document`, but clones `document` so the caller keeps
+  /// ownership of it.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the optional `base_url` cannot be parsed.
+  pub fn from_document_ref(
+    document: &dom_query::Document,
+    base_url: Option<&str>,
+    options: ReadabilityOptions,
+  ) -> Result<Self> {
+    Self::from_document(document.clone(), base_url, options)
+  }
+
+  /// Cheaply estimates whether the document contains an article worth
+  /// extracting. See `is_probably_readerable`.
+  #[must_use]
+  pub fn is_probably_readerable(&self, options: &ReaderableOptions) -> bool {
+    readerable::probably_readerable(&self.html, options)
+  }
+
+  /// Creates a new readability parser instance.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the optional `base_url` cannot be parsed.
+  pub fn new(
+    html: &str,
+    base_url: Option<&str>,
+    options: ReadabilityOptions,
+  ) -> Result<Self> {
+    Self::from_document(dom_query::Document::from(html), base_url, options)
+  }
+
   /// Extracts the article contents using the configured pipeline.
   ///
+  /// When the extracted text is shorter than
+  /// `ReadabilityOptions::min_text_length`, extraction is retried with one
+  /// more `Flag` disabled each time. Once every flag has been disabled, the
+  /// attempt with the most text wins.
+  ///
+  /// Every attempt runs on a copy of the document, so `document` stays
+  /// untouched and repeated calls yield the same article.
+  ///
   /// # Errors
   ///
-  /// Returns an error when the pipeline cannot resolve article content.
+  /// Returns an error when the pipeline fails, or
+  /// `Error::MissingArticleContent` when no attempt yields any text.
   pub fn parse(&mut self) -> Result<Article> {
-    let context = Pipeline::with_default_stages(
-      Context::new(&mut self.html, &self.options),
-      self.base_url.as_ref(),
+    self.run(false).map(|(article, _)| article)
+  }
+
+  /// Like `parse`, but also returns, for every attempt, how long each stage
+  /// took and how many elements the document held before and after it.
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as `parse`.
+  pub fn parse_with_report(&mut self) -> Result<(Article, ParseReport)> {
+    self.run(true)
+  }
+
+  fn run(&mut self, report: bool) -> Result<(Article, ParseReport)> {
+    let mut flags = Flag::ALL.to_vec();
+
+    let mut attempts = Vec::new();
+
+    loop {
+      let attempt = self.attempt(&flags, report)?;
+
+      let accepted = attempt.text_length >= self.options.min_text_length;
+
+      attempts.push(attempt);
+
+      if accepted {
+        let selected = attempts.len() - 1;
+        return Ok(self.finish(attempts, selected));
+      }
+
+      if flags.is_empty() {
+        break;
+      }
+
+      flags.remove(0);
+    }
+
+    let selected = (0..attempts.len())
+      .reduce(|best, i| {
+        if attempts[i].text_length > attempts[best].text_length {
+          i
+        } else {
+          best
+        }
+      })
+      .filter(|&i| attempts[i].text_length > 0)
+      .ok_or(Error::MissingArticleContent)?;
+
+    Ok(self.finish(attempts, selected))
+  }
+
+  /// Snapshots recorded by the attempt that produced the article returned by
+  /// the last call to `parse`, when `ReadabilityOptions::snapshots` is set.
+  ///
+  /// The first snapshot holds the input document; every following one was
+  /// taken after the stage it names.
+  #[must_use]
+  pub fn snapshots(&self) -> &[Snapshot] {
+    &self.snapshots
+  }
+
+  /// Replaces the options used by subsequent calls to `parse`.
+  #[must_use]
+  pub fn with_options(self, options: ReadabilityOptions) -> Self {
+    Self { options, ..self }
+  }
+
+  /// Replaces the default pipeline with `pipeline`, typically built with
+  /// `Pipeline::builder`.
+  #[must_use]
+  pub fn with_pipeline(self, pipeline: Pipeline) -> Self {
+    Self { pipeline, ..self }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::stage::TEXT};
+
+  fn parse(html: &str, options: ReadabilityOptions) -> Result<Article> {
+    Readability::new(html, None, options).unwrap().parse()
+  }
+
+  #[test]
+  fn missing_article_content() {
+    assert!(matches!(
+      parse("<html><body></body></html>", ReadabilityOptions::default()),
+      Err(Error::MissingArticleContent)
+    ));
+  }
+
+  #[test]
+  fn retries_without_stripping_unlikely_candidates() {
+    let article = parse(
+      &format!(
+        r#"<html><body><div class="comment"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ),
+      ReadabilityOptions::builder().min_text_length(200).build(),
     )
-    .run()?;
+    .unwrap();
 
-    let Metadata {
-      byline,
-      excerpt,
-      published_time,
-      site_name,
-      title,
-    } = context.metadata;
+    assert!(article.text_content.contains(TEXT));
+  }
 
-    Ok(Article {
-      byline,
-      content: context.document.html().to_string(),
-      dir: context.dir,
-      excerpt,
-      lang: context.lang,
-      length: context.document.text().to_string().len(),
-      published_time,
-      site_name,
-      text_content: context.document.text().to_string(),
-      title: title.unwrap_or_default(),
-    })
+  #[test]
+  fn returns_longest_attempt_when_all_are_short() {
+    let article = parse(
+      &format!(
+        r#"<html><body><div class="comment"><p>{TEXT}</p></div></body></html>"#
+      ),
+      ReadabilityOptions::default(),
+    )
+    .unwrap();
+
+    assert!(article.text_content.contains(TEXT));
+  }
+
+  #[test]
+  fn wraps_content_in_page_container() {
+    let article = parse(
+      &format!(
+        r"<html><head><title>foo</title></head><body><nav>bar</nav><article><p>{TEXT}</p><p>{TEXT}</p></article></body></html>"
+      ),
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      article.content,
+      format!(
+        r#"<div id="readability-page-1" class="page"><article><p>{TEXT}</p><p>{TEXT}</p></article></div>"#
+      )
+    );
+  }
+
+  #[test]
+  fn traces_stages_only_when_debug_is_set() {
+    let html = format!(
+      r"<html><body><div hidden>foo</div><article><p>{TEXT}</p></article></body></html>"
+    );
+
+    let sink = VecSink::default();
+
+    parse(
+      &html,
+      ReadabilityOptions::builder()
+        .min_text_length(0)
+        .trace_sink(sink.clone())
+        .build(),
+    )
+    .unwrap();
+
+    assert!(sink.events().is_empty());
+
+    parse(
+      &html,
+      ReadabilityOptions::builder()
+        .debug(true)
+        .min_text_length(0)
+        .trace_sink(sink.clone())
+        .build(),
+    )
+    .unwrap();
+
+    assert!(sink.events().contains(&TraceEvent {
+      kind: TraceEventKind::Removed { reason: "hidden" },
+      path: Some("html > body > div".into()),
+      stage: "RemoveHiddenNodes",
+    }));
+  }
+
+  #[test]
+  fn every_default_stage_traces() {
+    let html = format!(
+      r#"<html lang="en" dir="ltr"><head><title>foo &amp;amp; bar</title><script type="application/ld+json">{{ "@context": "https://schema.org", "@type": "Article", "datePublished": "2021-03-03" }}</script></head><body><img class="placeholder"><div hidden>hidden</div><div class="comment">comment</div><div></div><p class="byline">By qux</p><section>one<br><br>two <font>three</font></section><div id="main"><p class="intro">{TEXT}</p><p>{TEXT}</p><img src="hero.jpg"><table><tr><td>1</td><td>2</td></tr></table><ul class="related"><li>related</li></ul><p><a href="javascript:void(0)">link</a></p></div><section><p>{TEXT}</p><p>{TEXT}</p></section></body></html>"#
+    );
+
+    let sink = VecSink::default();
+
+    Readability::new(
+      &html,
+      Some("https://example.com/posts/1"),
+      ReadabilityOptions::builder()
+        .debug(true)
+        .max_elements(Some(1000))
+        .min_text_length(0)
+        .trace_sink(sink.clone())
+        .build(),
+    )
+    .unwrap()
+    .parse()
+    .unwrap();
+
+    let stages = sink
+      .events()
+      .into_iter()
+      .map(|event| event.stage)
+      .collect::<HashSet<_>>();
+
+    let silent = Pipeline::default()
+      .stage_names()
+      .into_iter()
+      .filter(|name| !stages.contains(name))
+      .collect::<Vec<_>>();
+
+    assert!(silent.is_empty(), "stages without trace events: {silent:?}");
+  }
+
+  #[test]
+  fn reports_custom_stage_failure() {
+    struct Fail;
+
+    impl Stage for Fail {
+      fn run(&mut self, _context: &mut Context<'_>) -> Result {
+        Err(Error::Stage {
+          name: self.name().into(),
+          source: "boom".into(),
+        })
+      }
+    }
+
+    let error = Readability::new(
+      &format!("<html><body><article><p>{TEXT}</p></article></body></html>"),
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap()
+    .with_pipeline(
+      Pipeline::builder()
+        .insert_before("ScoreCandidates", Fail)
+        .unwrap()
+        .build(),
+    )
+    .parse()
+    .unwrap_err();
+
+    assert_eq!(error.to_string(), "stage Fail failed: boom");
+  }
+
+  #[test]
+  fn runs_custom_pipeline() {
+    struct RemoveNewsletter;
+
+    impl Stage for RemoveNewsletter {
+      fn run(&mut self, context: &mut Context<'_>) -> Result {
+        context.dom().select(".newsletter").remove();
+        Ok(())
+      }
+    }
+
+    let pipeline = Pipeline::builder()
+      .insert_before("ScoreCandidates", RemoveNewsletter)
+      .unwrap()
+      .remove("WrapPage")
+      .unwrap()
+      .build();
+
+    let article = Readability::new(
+      &format!(
+        r#"<html><body><article><p>{TEXT}</p><p>{TEXT}</p><p class="newsletter">{TEXT} Subscribe!</p></article></body></html>"#
+      ),
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap()
+    .with_pipeline(pipeline)
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+      article.content,
+      format!("<article><p>{TEXT}</p><p>{TEXT}</p></article>")
+    );
+  }
+
+  #[test]
+  fn records_snapshots_when_enabled() {
+    let html = format!(
+      r"<html><body><div hidden>foo</div><article><p>{TEXT}</p></article></body></html>"
+    );
+
+    let mut readability = Readability::new(
+      &html,
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    readability.parse().unwrap();
+
+    assert!(readability.snapshots().is_empty());
+
+    let mut readability = Readability::new(
+      &html,
+      None,
+      ReadabilityOptions::builder()
+        .min_text_length(0)
+        .snapshots(true)
+        .build(),
+    )
+    .unwrap();
+
+    readability.parse().unwrap();
+
+    let snapshots = readability.snapshots();
+
+    assert_eq!(snapshots.len(), Pipeline::default().stage_names().len() + 1);
+    assert_eq!(snapshots[0].stage, None);
+
+    let index = snapshots
+      .iter()
+      .position(|snapshot| snapshot.stage == Some("RemoveHiddenNodes"))
+      .unwrap();
+
+    assert!(
+      snapshots[index]
+        .diff(&snapshots[index - 1])
+        .contains("-<div hidden=\"\">foo</div>")
+    );
+  }
+
+  #[test]
+  fn reports_each_stage() {
+    let html = format!(
+      r"<html><body><div hidden>foo</div><article><p>{TEXT}</p></article></body></html>"
+    );
+
+    let mut readability = Readability::new(
+      &html,
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    let (article, report) = readability.parse_with_report().unwrap();
+
+    assert!(article.text_content.contains(TEXT));
+
+    assert_eq!(report.attempts.len(), 1);
+    assert_eq!(report.selected, 0);
+
+    let stages = &report.attempts[0].stages;
+
+    assert_eq!(
+      stages.iter().map(|report| report.stage).collect::<Vec<_>>(),
+      Pipeline::default().stage_names()
+    );
+
+    let hidden = stages
+      .iter()
+      .find(|report| report.stage == "RemoveHiddenNodes")
+      .unwrap();
+
+    assert_eq!(hidden.elements_before, hidden.elements_after + 1);
+  }
+
+  #[test]
+  fn reports_every_attempt() {
+    let mut readability = Readability::new(
+      &format!("<html><body><article><p>{TEXT}</p></article></body></html>"),
+      None,
+      ReadabilityOptions::builder()
+        .min_text_length(10_000)
+        .build(),
+    )
+    .unwrap();
+
+    let (_, report) = readability.parse_with_report().unwrap();
+
+    assert_eq!(report.attempts.len(), Flag::ALL.len() + 1);
+    assert_eq!(report.selected, 0);
+
+    for attempt in &report.attempts {
+      assert_eq!(
+        attempt.stages.len(),
+        Pipeline::default().stage_names().len()
+      );
+      assert!(attempt.text_length > 0);
+    }
+
+    assert_eq!(
+      report.total_duration(),
+      report
+        .attempts
+        .iter()
+        .map(AttemptReport::total_duration)
+        .sum::<Duration>()
+    );
+  }
+
+  #[test]
+  fn decodes_bytes_and_reports_encoding() {
+    let html = format!(
+      r#"<html><head><meta charset="windows-1252"></head><body><article><p>Café. {TEXT}</p></article></body></html>"#
+    );
+
+    let mut readability = Readability::from_bytes(
+      &WINDOWS_1252.encode(&html).0,
+      None,
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    let article = readability.parse().unwrap();
+
+    assert!(article.text_content.contains("Café."));
+    assert_eq!(article.encoding.as_deref(), Some("windows-1252"));
+  }
+
+  #[test]
+  fn parses_existing_document() {
+    let document = dom_query::Document::from(format!(
+      r"<html><body><nav>foo</nav><article><p>{TEXT}</p></article></body></html>"
+    ));
+
+    let mut readability = Readability::from_document(
+      document,
+      None,
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    let article = readability.parse().unwrap();
+
+    assert!(article.text_content.contains(TEXT));
+
+    assert_eq!(readability.document().select("nav").length(), 1);
+  }
+
+  #[test]
+  fn parses_borrowed_document() {
+    let document = dom_query::Document::from(format!(
+      r"<html><body><nav>foo</nav><article><p>{TEXT}</p></article></body></html>"
+    ));
+
+    let options = ReadabilityOptions::builder().min_text_length(0).build();
+
+    let article =
+      Readability::from_document_ref(&document, None, options.clone())
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    assert!(article.text_content.contains(TEXT));
+
+    assert_eq!(document.select("nav").length(), 1);
+
+    assert_eq!(
+      Readability::from_document(document, None, options)
+        .unwrap()
+        .parse()
+        .unwrap(),
+      article
+    );
+  }
+
+  #[test]
+  fn parse_leaves_document_intact() {
+    let html = format!(
+      r#"<html><body><nav>foo</nav><article><p><a href="/bar">bar</a> {TEXT}</p></article></body></html>"#
+    );
+
+    let mut readability = Readability::new(
+      &html,
+      Some("https://example.com/"),
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap();
+
+    let before = readability.document().html().to_string();
+
+    let first = readability.parse().unwrap();
+
+    assert_eq!(readability.parse().unwrap(), first);
+
+    assert_eq!(readability.document().html().to_string(), before);
+
+    let mut readability = readability.with_options(
+      ReadabilityOptions::builder()
+        .min_text_length(10_000)
+        .build(),
+    );
+
+    assert_eq!(readability.parse().unwrap().content, first.content);
+  }
+
+  #[test]
+  fn keeps_forum_comments_with_custom_patterns() {
+    let html = format!(
+      r#"<html><body><article><p>{TEXT}</p><p>{TEXT}</p><div class="comment"><p>Reply. {TEXT}</p></div></article></body></html>"#
+    );
+
+    assert!(
+      !parse(
+        &html,
+        ReadabilityOptions::builder().min_text_length(0).build()
+      )
+      .unwrap()
+      .text_content
+      .contains("Reply.")
+    );
+
+    let options = ReadabilityOptions::builder()
+      .min_text_length(0)
+      .replace_pattern(Heuristic::UnlikelyCandidate, "sidebar|footer")
+      .unwrap()
+      .replace_pattern(Heuristic::Negative, "sidebar|footer")
+      .unwrap()
+      .build();
+
+    assert!(
+      parse(&html, options)
+        .unwrap()
+        .text_content
+        .contains("Reply.")
+    );
+  }
+
+  #[test]
+  fn uses_author_links_outside_article() {
+    let article = Readability::new(
+      &format!(
+        r#"<html><body><header><a rel="author" href="/people/jane">Jane Doe</a></header><aside class="sidebar"><a rel="author" href="/people/john">John Smith</a></aside><article><p>{TEXT}</p><p>{TEXT}</p></article></body></html>"#
+      ),
+      Some("https://example.com/posts/1"),
+      ReadabilityOptions::builder().min_text_length(0).build(),
+    )
+    .unwrap()
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+      article.authors,
+      [Author {
+        name: "Jane Doe".into(),
+        url: Some("https://example.com/people/jane".into()),
+      }]
+    );
   }
 }
@@ -0,0 +1,227 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct ReaderableOptions {
+  /// The minimum number of characters a node needs to contribute to the score.
+  ///
+  /// Default: 140
+  pub min_content_length: usize,
+  /// The score a document needs to exceed to be considered readerable.
+  ///
+  /// Default: 20.0
+  pub min_score: f64,
+}
+
+impl Default for ReaderableOptions {
+  fn default() -> Self {
+    Self {
+      min_content_length: 140,
+      min_score: 20.0,
+    }
+  }
+}
+
+impl ReaderableOptions {
+  #[must_use]
+  pub fn builder() -> ReaderableOptionsBuilder {
+    ReaderableOptionsBuilder::default()
+  }
+}
+
+#[derive(Default)]
+pub struct ReaderableOptionsBuilder {
+  inner: ReaderableOptions,
+}
+
+impl ReaderableOptionsBuilder {
+  #[must_use]
+  pub fn build(self) -> ReaderableOptions {
+    self.inner
+  }
+
+  #[must_use]
+  pub fn min_content_length(self, min_content_length: usize) -> Self {
+    Self {
+      inner: ReaderableOptions {
+        min_content_length,
+        ..self.inner
+      },
+    }
+  }
+
+  #[must_use]
+  pub fn min_score(self, min_score: f64) -> Self {
+    Self {
+      inner: ReaderableOptions {
+        min_score,
+        ..self.inner
+      },
+    }
+  }
+}
+
+/// Cheaply estimates whether `html` contains an article worth extracting,
+/// without running the extraction pipeline.
+///
+/// Visible `<p>`, `<pre>`, and `<article>` elements, along with `<div>`
+/// elements containing a `<br>`, each contribute
+/// `sqrt(length - min_content_length)` when their text is at least
+/// `ReaderableOptions::min_content_length` characters long. Nodes whose class
+/// or id looks unlikely to be content, and paragraphs inside list items, are
+/// skipped. The document is readerable once the score exceeds
+/// `ReaderableOptions::min_score`.
+#[must_use]
+pub fn is_probably_readerable(html: &str, options: &ReaderableOptions) -> bool {
+  probably_readerable(&dom_query::Document::from(html), options)
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn probably_readerable(
+  document: &dom_query::Document,
+  options: &ReaderableOptions,
+) -> bool {
+  let mut seen = HashSet::new();
+
+  let nodes = document
+    .select("p, pre, article")
+    .nodes()
+    .iter()
+    .cloned()
+    .chain(
+      document
+        .select("div > br")
+        .nodes()
+        .iter()
+        .filter_map(NodeRef::parent),
+    )
+    .filter(|node| seen.insert(node.id))
+    .collect::<Vec<_>>();
+
+  let mut score = 0.0;
+
+  for node in nodes {
+    if RemoveHiddenNodes::is_hidden(&node) {
+      continue;
+    }
+
+    let match_string = format!(
+      "{} {}",
+      node.attr("class").unwrap_or_default(),
+      node.attr("id").unwrap_or_default()
+    );
+
+    if UNLIKELY_CANDIDATE.is_match(&match_string)
+      && !MAYBE_CANDIDATE.is_match(&match_string)
+    {
+      continue;
+    }
+
+    if node.is("li p") {
+      continue;
+    }
+
+    let length = node.text().trim().chars().count();
+
+    if length < options.min_content_length {
+      continue;
+    }
+
+    score += ((length - options.min_content_length) as f64).sqrt();
+
+    if score > options.min_score {
+      return true;
+    }
+  }
+
+  false
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn paragraph(length: usize) -> String {
+    format!("<p>{}</p>", "a".repeat(length))
+  }
+
+  #[test]
+  fn empty_document_is_not_readerable() {
+    assert!(!is_probably_readerable(
+      "<html><body></body></html>",
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn ignores_hidden_nodes() {
+    assert!(!is_probably_readerable(
+      &paragraph(1000).replace("<p>", "<p hidden>"),
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn ignores_paragraphs_in_list_items() {
+    assert!(!is_probably_readerable(
+      &format!("<ul><li>{}</li></ul>", paragraph(1000)),
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn ignores_unlikely_candidates() {
+    assert!(!is_probably_readerable(
+      &paragraph(1000).replace("<p>", r#"<p class="sidebar">"#),
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn keeps_maybe_candidates() {
+    assert!(is_probably_readerable(
+      &paragraph(1000).replace("<p>", r#"<p class="sidebar content">"#),
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn long_paragraph_is_readerable() {
+    assert!(is_probably_readerable(
+      &paragraph(1000),
+      &ReaderableOptions::default()
+    ));
+  }
+
+  #[test]
+  fn respects_min_content_length() {
+    let html = paragraph(450);
+
+    assert!(!is_probably_readerable(
+      &html,
+      &ReaderableOptions::default()
+    ));
+
+    assert!(is_probably_readerable(
+      &html,
+      &ReaderableOptions::builder().min_content_length(0).build()
+    ));
+  }
+
+  #[test]
+  fn respects_min_score() {
+    let html = paragraph(1000);
+
+    assert!(!is_probably_readerable(
+      &html,
+      &ReaderableOptions::builder().min_score(100.0).build()
+    ));
+  }
+
+  #[test]
+  fn scores_divs_with_line_breaks() {
+    assert!(is_probably_readerable(
+      &format!("<div>{}<br>{}</div>", "a".repeat(500), "a".repeat(500)),
+      &ReaderableOptions::default()
+    ));
+  }
+}
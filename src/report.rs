@@ -0,0 +1,208 @@
+use super::*;
+
+/// Per-stage timing and element counts of a single pipeline run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AttemptReport {
+  /// One entry per stage, in the order the stages ran.
+  pub stages: Vec<StageReport>,
+  /// Number of characters in the article the attempt extracted.
+  pub text_length: usize,
+}
+
+impl AttemptReport {
+  /// Sum of the durations of every stage of the attempt.
+  #[must_use]
+  pub fn total_duration(&self) -> Duration {
+    self.stages.iter().map(|report| report.duration).sum()
+  }
+}
+
+/// Per-stage timing and element counts of every pipeline run, returned by
+/// `Readability::parse_with_report`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ParseReport {
+  /// One entry per pipeline run, in the order they ran.
+  pub attempts: Vec<AttemptReport>,
+  /// Index into `attempts` of the attempt that produced the article.
+  pub selected: usize,
+}
+
+impl Display for ParseReport {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let width = self
+      .attempts
+      .iter()
+      .flat_map(|attempt| &attempt.stages)
+      .map(|report| report.stage.len())
+      .chain(iter::once("stage".len()))
+      .max()
+      .unwrap_or_default();
+
+    for (i, attempt) in self.attempts.iter().enumerate() {
+      writeln!(
+        f,
+        "attempt {} of {}{}: {} characters",
+        i + 1,
+        self.attempts.len(),
+        if i == self.selected {
+          " (selected)"
+        } else {
+          ""
+        },
+        attempt.text_length
+      )?;
+
+      writeln!(
+        f,
+        "{:width$}  {:>12}  {:>8}  {:>8}",
+        "stage", "time (µs)", "before", "after"
+      )?;
+
+      for report in &attempt.stages {
+        writeln!(
+          f,
+          "{:width$}  {:>12}  {:>8}  {:>8}",
+          report.stage,
+          report.duration.as_micros(),
+          report.elements_before,
+          report.elements_after
+        )?;
+      }
+
+      writeln!(
+        f,
+        "{:width$}  {:>12}",
+        "total",
+        attempt.total_duration().as_micros()
+      )?;
+
+      writeln!(f)?;
+    }
+
+    write!(
+      f,
+      "total across attempts (µs): {}",
+      self.total_duration().as_micros()
+    )
+  }
+}
+
+impl ParseReport {
+  /// Report of the attempt that produced the article.
+  #[must_use]
+  pub fn selected(&self) -> Option<&AttemptReport> {
+    self.attempts.get(self.selected)
+  }
+
+  /// Sum of the durations of every stage across all attempts.
+  #[must_use]
+  pub fn total_duration(&self) -> Duration {
+    self
+      .attempts
+      .iter()
+      .map(AttemptReport::total_duration)
+      .sum()
+  }
+}
+
+/// Wall-clock duration and element counts for a single stage.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageReport {
+  /// Time the stage took to run, serialized as fractional seconds.
+  #[serde(serialize_with = "serialize_seconds")]
+  pub duration: Duration,
+  /// Number of elements in the document after the stage ran.
+  pub elements_after: usize,
+  /// Number of elements in the document before the stage ran.
+  pub elements_before: usize,
+  /// Name of the stage.
+  pub stage: &'static str,
+}
+
+fn serialize_seconds<S: serde::Serializer>(
+  duration: &Duration,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.serialize_f64(duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn report() -> ParseReport {
+    ParseReport {
+      attempts: vec![
+        AttemptReport {
+          stages: vec![StageReport {
+            duration: Duration::from_micros(7),
+            elements_after: 8,
+            elements_before: 12,
+            stage: "RemoveHiddenNodes",
+          }],
+          text_length: 42,
+        },
+        AttemptReport {
+          stages: vec![
+            StageReport {
+              duration: Duration::from_micros(15),
+              elements_after: 10,
+              elements_before: 12,
+              stage: "RemoveHiddenNodes",
+            },
+            StageReport {
+              duration: Duration::from_micros(5),
+              elements_after: 10,
+              elements_before: 10,
+              stage: "ExtractTitle",
+            },
+          ],
+          text_length: 500,
+        },
+      ],
+      selected: 1,
+    }
+  }
+
+  #[test]
+  fn display_renders_table() {
+    assert_eq!(
+      report().to_string(),
+      [
+        "attempt 1 of 2: 42 characters",
+        "stage                 time (µs)    before     after",
+        "RemoveHiddenNodes             7        12         8",
+        "total                         7",
+        "",
+        "attempt 2 of 2 (selected): 500 characters",
+        "stage                 time (µs)    before     after",
+        "RemoveHiddenNodes            15        12        10",
+        "ExtractTitle                  5        10        10",
+        "total                        20",
+        "",
+        "total across attempts (µs): 27",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[test]
+  fn selected_attempt() {
+    let report = report();
+    assert_eq!(report.selected().unwrap().text_length, 500);
+    assert_eq!(report.total_duration(), Duration::from_micros(27));
+  }
+
+  #[test]
+  fn serializes_durations_as_seconds() {
+    assert_eq!(
+      serde_json::to_value(report()).unwrap()["attempts"][1]["stages"][0],
+      serde_json::json!({
+        "duration": 0.000_015,
+        "elements_after": 10,
+        "elements_before": 12,
+        "stage": "RemoveHiddenNodes",
+      })
+    );
+  }
+}
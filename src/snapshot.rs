@@ -0,0 +1,155 @@
+use super::*;
+
+/// Number of unchanged lines shown around each change in `Snapshot::diff`.
+const DIFF_CONTEXT: usize = 3;
+
+/// The serialized document and metadata captured after a pipeline stage ran,
+/// recorded when `ReadabilityOptions::snapshots` is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+  /// Serialized document.
+  pub html: String,
+  /// Metadata extracted so far.
+  pub metadata: Metadata,
+  /// Stage that had just run, or `None` for the document before any stage.
+  pub stage: Option<&'static str>,
+}
+
+impl Snapshot {
+  pub(crate) fn capture(
+    context: &Context,
+    stage: Option<&'static str>,
+  ) -> Self {
+    Self {
+      html: context.document.html().to_string(),
+      metadata: context.metadata.clone(),
+      stage,
+    }
+  }
+
+  /// Returns a unified diff from `previous` to this snapshot, or an empty
+  /// string when nothing changed.
+  ///
+  /// Metadata is rendered one field per line, and the document is split into
+  /// one line per tag so that removed or rewritten nodes stand out.
+  #[must_use]
+  pub fn diff(&self, previous: &Snapshot) -> String {
+    let (old, new) = (previous.lines(), self.lines());
+
+    let mut entries = Vec::new();
+
+    let (mut old_line, mut new_line) = (1, 1);
+
+    for result in diff::slice(&old, &new) {
+      match result {
+        diff::Result::Left(line) => {
+          entries.push(('-', line, old_line, new_line));
+          old_line += 1;
+        }
+        diff::Result::Right(line) => {
+          entries.push(('+', line, old_line, new_line));
+          new_line += 1;
+        }
+        diff::Result::Both(line, _) => {
+          entries.push((' ', line, old_line, new_line));
+          old_line += 1;
+          new_line += 1;
+        }
+      }
+    }
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+
+    for (index, _) in entries.iter().enumerate().filter(|(_, e)| e.0 != ' ') {
+      let start = index.saturating_sub(DIFF_CONTEXT);
+      let end = (index + DIFF_CONTEXT + 1).min(entries.len());
+
+      match hunks.last_mut() {
+        Some(hunk) if start <= hunk.1 => hunk.1 = end,
+        _ => hunks.push((start, end)),
+      }
+    }
+
+    if hunks.is_empty() {
+      return String::new();
+    }
+
+    let mut output = vec![
+      format!("--- {}", previous.stage.unwrap_or("input")),
+      format!("+++ {}", self.stage.unwrap_or("input")),
+    ];
+
+    for (start, end) in hunks {
+      let hunk = &entries[start..end];
+
+      let old_length = hunk.iter().filter(|entry| entry.0 != '+').count();
+      let new_length = hunk.iter().filter(|entry| entry.0 != '-').count();
+
+      output.push(format!(
+        "@@ -{},{old_length} +{},{new_length} @@",
+        hunk[0].2, hunk[0].3
+      ));
+
+      output
+        .extend(hunk.iter().map(|(tag, line, _, _)| format!("{tag}{line}")));
+    }
+
+    output.push(String::new());
+
+    output.join("\n")
+  }
+
+  fn lines(&self) -> Vec<String> {
+    format!("{:#?}", self.metadata)
+      .lines()
+      .chain(self.html.replace("><", ">\n<").lines())
+      .map(str::to_owned)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn snapshot(stage: &'static str, html: &str) -> Snapshot {
+    Snapshot {
+      html: html.into(),
+      metadata: Metadata::default(),
+      stage: Some(stage),
+    }
+  }
+
+  #[test]
+  fn diff_is_empty_without_changes() {
+    let html = "<html><head></head><body><p>foo</p></body></html>";
+
+    assert_eq!(snapshot("B", html).diff(&snapshot("A", html)), "");
+  }
+
+  #[test]
+  fn diff_shows_removed_node_with_context() {
+    let before = snapshot(
+      "A",
+      "<html><head></head><body><p>foo</p><div>bar</div></body></html>",
+    );
+
+    let after =
+      snapshot("B", "<html><head></head><body><p>foo</p></body></html>");
+
+    assert_eq!(
+      after.diff(&before),
+      concat!(
+        "--- A\n",
+        "+++ B\n",
+        "@@ -17,6 +17,5 @@\n",
+        " </head>\n",
+        " <body>\n",
+        " <p>foo</p>\n",
+        "-<div>bar</div>\n",
+        " </body>\n",
+        " </html>\n",
+      )
+    );
+  }
+}
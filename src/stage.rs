@@ -1,42 +1,82 @@
 use super::*;
 
+mod clean_attributes;
+mod clean_conditionally;
 mod element_limit;
+mod extract_authors;
 mod extract_byline;
 mod extract_dir;
 mod extract_excerpt;
+mod extract_image;
 mod extract_json_ld;
 mod extract_lang;
 mod extract_meta_tags;
 mod extract_title;
+mod mark_data_tables;
+mod merge_siblings;
+mod parse_published_time;
 mod remove_disallowed_nodes;
 mod remove_empty_containers;
 mod remove_hidden_nodes;
 mod remove_unlikely_candidates;
+mod resolve_relative_urls;
 mod rewrite_font_tags;
 mod rewrite_line_breaks;
+mod score_candidates;
 mod unescape_html_entities;
 mod unwrap_noscript_images;
+mod wrap_page;
 
 #[cfg(test)]
 mod test;
 
 pub(crate) use {
-  element_limit::ElementLimit, extract_byline::ExtractByline,
-  extract_dir::ExtractDir, extract_excerpt::ExtractExcerpt,
+  clean_attributes::CleanAttributes, clean_conditionally::CleanConditionally,
+  element_limit::ElementLimit, extract_authors::ExtractAuthors,
+  extract_byline::ExtractByline, extract_dir::ExtractDir,
+  extract_excerpt::ExtractExcerpt, extract_image::ExtractImage,
   extract_json_ld::ExtractJsonLd, extract_lang::ExtractLang,
   extract_meta_tags::ExtractMetaTags, extract_title::ExtractTitle,
+  mark_data_tables::MarkDataTables, merge_siblings::MergeSiblings,
+  parse_published_time::ParsePublishedTime,
   remove_disallowed_nodes::RemoveDisallowedNodes,
   remove_empty_containers::RemoveEmptyContainers,
   remove_hidden_nodes::RemoveHiddenNodes,
   remove_unlikely_candidates::RemoveUnlikelyCandidates,
+  resolve_relative_urls::ResolveRelativeUrls,
   rewrite_font_tags::RewriteFontTags, rewrite_line_breaks::RewriteLineBreaks,
+  score_candidates::ScoreCandidates,
   unescape_html_entities::UnescapeHtmlEntities,
-  unwrap_noscript_images::UnwrapNoscriptImages,
+  unwrap_noscript_images::UnwrapNoscriptImages, wrap_page::WrapPage,
 };
 
 #[cfg(test)]
-pub(crate) use test::Test;
+pub(crate) use test::{TEXT, Test};
 
-pub(crate) trait Stage {
+/// A single step of a `Pipeline`.
+///
+/// Stages inspect and mutate the document through `Context`. Implement this
+/// trait to add custom cleanup to a pipeline built with `Pipeline::builder`.
+pub trait Stage {
+  /// Name used to refer to the stage in `PipelineBuilder`, defaulting to the
+  /// type name without its module path or generic arguments.
+  fn name(&self) -> &'static str {
+    let name = type_name::<Self>();
+
+    name
+      .split('<')
+      .next()
+      .unwrap_or(name)
+      .rsplit("::")
+      .next()
+      .unwrap_or_default()
+  }
+
+  /// Runs the stage over `context`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error to abort parsing. Custom stages report their own
+  /// failures as `Error::Stage`.
   fn run(&mut self, context: &mut Context<'_>) -> Result;
 }
@@ -0,0 +1,218 @@
+use super::*;
+
+/// Attributes that only affect presentation and are always stripped.
+const PRESENTATIONAL_ATTRIBUTES: &[&str] = &[
+  "align",
+  "background",
+  "bgcolor",
+  "border",
+  "cellpadding",
+  "cellspacing",
+  "frame",
+  "hspace",
+  "rules",
+  "style",
+  "valign",
+  "vspace",
+];
+
+/// Attributes stripped from every element except those in `TABLE_ELEMENTS`.
+const SIZE_ATTRIBUTES: &[&str] = &["width", "height"];
+
+/// Elements allowed to keep their `SIZE_ATTRIBUTES`.
+const TABLE_ELEMENTS: &[&str] = &[
+  "table", "caption", "colgroup", "col", "thead", "tbody", "tfoot", "tr", "th",
+  "td",
+];
+
+/// Strips presentational attributes and source-site classes from
+/// `Context::article`.
+///
+/// Attributes in `PRESENTATIONAL_ATTRIBUTES` are removed everywhere, and
+/// `width` and `height` are removed from everything but table elements.
+/// Unless `ReadabilityOptions::keep_classes` is set, every class not listed in
+/// `ReadabilityOptions::preserved_classes` is removed, dropping the `class`
+/// attribute once it is empty. Attributes listed in
+/// `ReadabilityOptions::preserved_attributes` are never touched. Elements
+/// inside `<svg>` are left alone.
+pub(crate) struct CleanAttributes;
+
+impl Stage for CleanAttributes {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    let Some(article) = context
+      .article
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    let options = context.options();
+
+    let preserved = |attribute: &str| {
+      options
+        .preserved_attributes
+        .iter()
+        .any(|preserved| preserved.eq_ignore_ascii_case(attribute))
+    };
+
+    let mut elements = article.element_descendants();
+
+    elements.insert(0, article);
+
+    for element in elements {
+      if element.is("svg, svg *") {
+        continue;
+      }
+
+      let before = context.tracing().then(|| element.attrs());
+
+      for attribute in PRESENTATIONAL_ATTRIBUTES {
+        if !preserved(attribute) {
+          element.remove_attr(attribute);
+        }
+      }
+
+      if !TABLE_ELEMENTS
+        .contains(&element.node_name().unwrap_or_default().as_ref())
+      {
+        for attribute in SIZE_ATTRIBUTES {
+          if !preserved(attribute) {
+            element.remove_attr(attribute);
+          }
+        }
+      }
+
+      if !options.keep_classes && !preserved("class") {
+        Self::clean_classes(&element, &options.preserved_classes);
+      }
+
+      if before.is_some_and(|before| before != element.attrs()) {
+        context.trace(
+          Some(&element),
+          TraceEventKind::Message("stripped attributes".into()),
+        );
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl CleanAttributes {
+  fn clean_classes(element: &NodeRef, preserved_classes: &[String]) {
+    let Some(class) = element.attr("class") else {
+      return;
+    };
+
+    let classes = class
+      .split_whitespace()
+      .filter(|class| {
+        preserved_classes.iter().any(|preserved| preserved == class)
+      })
+      .collect::<Vec<_>>();
+
+    if classes.is_empty() {
+      element.remove_attr("class");
+    } else {
+      element.set_attr("class", &classes.join(" "));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_all_classes_with_keep_classes() {
+    let content = r#"<p class="foo page">bar</p>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(content)
+      .options(ReadabilityOptions::builder().keep_classes(true).build())
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_preserved_attributes() {
+    let content = r#"<p style="color: red" align="center">foo</p>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(content)
+      .options(
+        ReadabilityOptions::builder()
+          .attributes_to_preserve(["style"])
+          .build(),
+      )
+      .expected_article(r#"<p style="color: red">foo</p>"#)
+      .run();
+  }
+
+  #[test]
+  fn keeps_preserved_classes() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(r#"<p class="foo page bar">baz</p>"#)
+      .expected_article(r#"<p class="page">baz</p>"#)
+      .run();
+  }
+
+  #[test]
+  fn keeps_size_attributes_on_table_elements() {
+    let content = r#"<table width="100"><tbody><tr><td height="10">foo</td></tr></tbody></table>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn leaves_svg_alone() {
+    let content = r#"<svg width="10" style="fill: red"><rect class="foo" width="5"></rect></svg>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn removes_classes() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(r#"<p class="foo bar">baz</p>"#)
+      .expected_article("<p>baz</p>")
+      .run();
+  }
+
+  #[test]
+  fn removes_presentational_attributes() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanAttributes)
+      .article(
+        r#"<p align="center" style="color: red" bgcolor="red">foo<img src="a.png" width="10" height="10"></p>"#,
+      )
+      .expected_article(r#"<p>foo<img src="a.png"></p>"#)
+      .run();
+  }
+}
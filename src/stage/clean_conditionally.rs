@@ -0,0 +1,444 @@
+use super::*;
+
+/// Lists, tables, and containers with at least this many commas are kept.
+const MIN_COMMAS: usize = 10;
+
+/// Shorter content is removed when it is mostly links and headings.
+const MIN_CONTENT_LENGTH: usize = 25;
+
+/// A single embed is only tolerated next to at least this much text.
+const MIN_EMBED_CONTENT_LENGTH: usize = 75;
+
+/// Number of `<li>` elements tolerated beyond the number of paragraphs.
+const LIST_ITEM_ALLOWANCE: usize = 100;
+
+/// Link density tolerated in nodes with a non-positive class weight.
+const MAX_LINK_DENSITY: f64 = 0.2;
+
+/// Link density tolerated in nodes with a strongly positive class weight.
+const MAX_WEIGHTED_LINK_DENSITY: f64 = 0.5;
+
+/// Depth searched for `<code>` and `<figure>` ancestors.
+const MAX_ANCESTOR_DEPTH: usize = 4;
+
+/// Tags removed from the article when they look like boilerplate, in the
+/// order they are processed.
+const TAGS: &[&str] = &["form", "fieldset", "table", "ul", "div", "section"];
+
+/// Tags whose text counts as prose when computing text density.
+const TEXTISH_TAGS: &[&str] = &[
+  "span",
+  "li",
+  "td",
+  "blockquote",
+  "dl",
+  "div",
+  "img",
+  "ol",
+  "p",
+  "pre",
+  "table",
+  "ul",
+];
+
+/// Removes forms, tables, lists, and containers from `Context::article` that
+/// look like share widgets, related links, or ads.
+///
+/// Containers holding an embed allowed by
+/// `ReadabilityOptions::allowed_video_regex` are always kept.
+///
+/// Data tables recorded in `Context::data_tables` by `MarkDataTables`, nodes
+/// inside them or containing them, and nodes inside `<code>` are always kept.
+/// Any other node is removed when its class weight is negative, or when it has
+/// fewer than `MIN_COMMAS` commas and either reads as an ad or loading
+/// placeholder, or trips one of the image, list, input, heading, link density,
+/// embed, or text density checks. Lists made up solely of images are kept.
+///
+/// The class weight only applies when `Flag::WeightClasses` is active, and the
+/// whole stage is skipped without `Flag::CleanConditionally`.
+pub(crate) struct CleanConditionally;
+
+impl Stage for CleanConditionally {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    if !context.has_flag(Flag::CleanConditionally) {
+      return Ok(());
+    }
+
+    let Some(article) = context
+      .article
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    for tag in TAGS {
+      let nodes = Selection::from(article.clone())
+        .select(tag)
+        .nodes()
+        .to_vec();
+
+      for node in nodes.iter().rev() {
+        if let Some(reason) = Self::removal_reason(context, node, tag) {
+          context.trace(Some(node), TraceEventKind::Removed { reason });
+          node.remove_from_parent();
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl CleanConditionally {
+  fn count(node: &NodeRef, selector: &str) -> usize {
+    Selection::from(node.clone()).select(selector).length()
+  }
+
+  fn has_ancestor(
+    node: &NodeRef,
+    max_depth: Option<usize>,
+    predicate: impl Fn(&NodeRef) -> bool,
+  ) -> bool {
+    node.ancestors(max_depth).iter().any(predicate)
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  fn is_list(node: &NodeRef, tag: &str) -> bool {
+    if matches!(tag, "ul" | "ol") {
+      return true;
+    }
+
+    let text_length = node.inner_text().chars().count();
+
+    if text_length == 0 {
+      return false;
+    }
+
+    let list_length = Selection::from(node.clone())
+      .select("ul, ol")
+      .nodes()
+      .iter()
+      .map(|list| list.inner_text().chars().count())
+      .sum::<usize>();
+
+    list_length as f64 / text_length as f64 > 0.9
+  }
+
+  fn removal_reason(
+    context: &Context,
+    node: &NodeRef,
+    tag: &str,
+  ) -> Option<&'static str> {
+    let is_data_table = |node: &NodeRef| context.data_tables.contains(&node.id);
+
+    if (tag == "table" && is_data_table(node))
+      || Self::has_ancestor(node, None, |ancestor| {
+        ancestor.is("table") && is_data_table(ancestor)
+      })
+      || Self::has_ancestor(node, Some(MAX_ANCESTOR_DEPTH), |ancestor| {
+        ancestor.is("code")
+      })
+      || Selection::from(node.clone())
+        .select("table")
+        .nodes()
+        .iter()
+        .any(is_data_table)
+      || node.contains_allowed_video(&context.options().allowed_video_regex)
+    {
+      return None;
+    }
+
+    let weight = if context.has_flag(Flag::WeightClasses) {
+      node.class_weight(&context.options().patterns)
+    } else {
+      0.0
+    };
+
+    if weight < 0.0 {
+      return Some("negative class weight");
+    }
+
+    let text = node.inner_text();
+
+    if text.matches(',').count() >= MIN_COMMAS {
+      return None;
+    }
+
+    if AD_WORDS.is_match(&text) || LOADING_WORDS.is_match(&text) {
+      return Some("ad or loading placeholder");
+    }
+
+    let embeds = Self::count(node, "object, embed, iframe");
+
+    let is_list = Self::is_list(node, tag);
+
+    let is_figure_child =
+      Self::has_ancestor(node, Some(MAX_ANCESTOR_DEPTH), |ancestor| {
+        ancestor.is("figure")
+      });
+
+    let paragraphs = Self::count(node, "p");
+    let images = Self::count(node, "img");
+    let list_items = Self::count(node, "li");
+    let inputs = Self::count(node, "input");
+
+    let content_length = text.chars().count();
+    let link_density = node.link_density(context.link_density_bias());
+
+    #[allow(clippy::cast_precision_loss)]
+    let reason = if !is_figure_child
+      && images > 1
+      && (paragraphs as f64) / (images as f64) < 0.5
+    {
+      Some("more images than paragraphs")
+    } else if !is_list && list_items > paragraphs + LIST_ITEM_ALLOWANCE {
+      Some("more list items than paragraphs")
+    } else if inputs > paragraphs / 3 {
+      Some("too many inputs")
+    } else if !is_list
+      && !is_figure_child
+      && node.text_density(&["h1", "h2", "h3", "h4", "h5", "h6"]) < 0.9
+      && content_length < MIN_CONTENT_LENGTH
+      && (images == 0 || images > 2)
+      && link_density > 0.0
+    {
+      Some("short content with links")
+    } else if !is_list && weight < 25.0 && link_density > MAX_LINK_DENSITY {
+      Some("high link density")
+    } else if weight >= 25.0 && link_density > MAX_WEIGHTED_LINK_DENSITY {
+      Some("high link density despite positive class weight")
+    } else if (embeds == 1 && content_length < MIN_EMBED_CONTENT_LENGTH)
+      || embeds > 1
+    {
+      Some("embeds without enough content")
+    } else if images == 0 && node.text_density(TEXTISH_TAGS) == 0.0 {
+      Some("no images or text")
+    } else {
+      None
+    };
+
+    if is_list
+      && reason.is_some()
+      && !node
+        .element_children()
+        .iter()
+        .any(|child| child.element_children().len() > 1)
+      && images == list_items
+    {
+      return None;
+    }
+
+    reason
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_allowed_video_embed() {
+    let content = r#"<section><iframe src="https://www.youtube.com/embed/foo"></iframe></section>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_custom_allowed_video_embed() {
+    let content =
+      r#"<section><iframe src="https://video.example/1"></iframe></section>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .options(
+        ReadabilityOptions::builder()
+          .allowed_video_regex(Regex::new("//video\\.example/").unwrap())
+          .build(),
+      )
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_allowed_video_in_negatively_weighted_container() {
+    let content = r#"<section class="sidebar"><iframe src="https://www.youtube.com/embed/foo"></iframe></section>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_code_blocks() {
+    let content =
+      r#"<pre><code><section><a href="/foo">foo</a></section></code></pre>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_container_without_custom_negative_match() {
+    let content =
+      format!(r#"<section class="comment"><p>{TEXT}</p></section>"#);
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(&content)
+      .options(
+        ReadabilityOptions::builder()
+          .replace_pattern(Heuristic::Negative, "sidebar|footer")
+          .unwrap()
+          .build(),
+      )
+      .expected_article(&content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_image_list() {
+    let content =
+      r#"<ul><li><img src="a.png"></li><li><img src="b.png"></li></ul>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn keeps_nodes_without_clean_conditionally_flag() {
+    let content =
+      r#"<section class="share"><a href="/foo">Share</a></section>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(content)
+      .flags(&[Flag::StripUnlikelys, Flag::WeightClasses])
+      .expected_article(content)
+      .run();
+  }
+
+  #[test]
+  fn removes_advertisement_placeholder() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article("<section>Advertisement</section>")
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn removes_form_with_inputs() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(r#"<form><input type="text"><input type="submit"></form>"#)
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn removes_link_heavy_section() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(r#"<section><a href="/a">Share on social media</a></section>"#)
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn removes_negatively_weighted_container() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(&format!(
+        r#"<section class="comment"><p>{TEXT}</p></section>"#
+      ))
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn removes_negatively_weighted_list() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(
+        r#"<ul class="related"><li><a href="/a">Related article one</a></li><li><a href="/b">Related article two</a></li></ul>"#,
+      )
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn removes_unknown_embeds() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(
+        r#"<section><iframe src="https://ads.example.com/"></iframe></section>"#,
+      )
+      .expected_article("")
+      .run();
+  }
+
+  #[test]
+  fn traces_removal_reason() {
+    let sink = VecSink::default();
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(CleanConditionally)
+      .article(r#"<ul class="related"><li>foo</li></ul>"#)
+      .options(
+        ReadabilityOptions::builder()
+          .debug(true)
+          .trace_sink(sink.clone())
+          .build(),
+      )
+      .run();
+
+    assert!(sink.events().contains(&TraceEvent {
+      kind: TraceEventKind::Removed {
+        reason: "negative class weight",
+      },
+      path: Some("html > body > div > div#main > ul.related".into()),
+      stage: "CleanConditionally",
+    }));
+  }
+}
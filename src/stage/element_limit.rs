@@ -15,6 +15,15 @@ impl Stage for ElementLimit {
 
     let count = context.document().element_count();
 
+    if context.tracing() {
+      context.trace(
+        None,
+        TraceEventKind::Message(
+          format!("{count} elements, limit {limit}").into(),
+        ),
+      );
+    }
+
     if count > limit {
       return Err(Error::ElementLimitExceeded {
         found: count,
@@ -0,0 +1,292 @@
+use super::*;
+
+/// Parts of a byline with more words than this are not taken as names.
+const MAX_NAME_WORDS: usize = 5;
+
+/// Words a name must have after a prefix that doubles as a surname particle,
+/// such as "Di" or "Von", for the prefix to be dropped.
+const MIN_PARTICLE_NAME_WORDS: usize = 2;
+
+/// Fills `Metadata::authors` when `ExtractJsonLd` found none.
+///
+/// Links with `rel="author"` in the byline area are used first: inside a
+/// `<header>`, or inside an element whose class and id match
+/// `Patterns::byline`. Failing that, links inside an `<article>` element are
+/// used. Their `href` is resolved against `Context::base_url`. Links
+/// elsewhere, such as in sidebars or comments, are ignored. The stage runs
+/// before `MergeSiblings` detaches everything outside the article.
+///
+/// Without links, `Metadata::byline` is split into names: a leading "By" or
+/// one of its translations, anything after a date or a separator such as `|`,
+/// and roles such as "Staff Writer" are dropped, and the remainder is split on
+/// commas and on "and" in several languages.
+/// Translations that double as surname particles, such as "Di" in "Di Maio",
+/// are only dropped when followed by a colon or by a name of at least
+/// `MIN_PARTICLE_NAME_WORDS` words.
+pub(crate) struct ExtractAuthors;
+
+impl Stage for ExtractAuthors {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    if !context.metadata.authors.is_empty() {
+      return Ok(());
+    }
+
+    let mut authors = Vec::<Author>::new();
+
+    let mut links = context
+      .document
+      .select("a[rel~='author']")
+      .nodes()
+      .iter()
+      .filter(|link| link.in_byline_area(&context.options.patterns.byline))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    if links.is_empty() {
+      links = context
+        .document
+        .select("article a[rel~='author']")
+        .nodes()
+        .to_vec();
+    }
+
+    for link in &links {
+      let name = NORMALIZE_WHITESPACE
+        .replace_all(link.text().trim(), " ")
+        .to_string();
+
+      if name.is_empty()
+        || name.split_whitespace().count() > MAX_NAME_WORDS
+        || authors.iter().any(|author| author.name == name)
+      {
+        continue;
+      }
+
+      let url = link.attr("href").map(|href| match context.base_url {
+        Some(base_url) => base_url
+          .join(href.trim())
+          .map_or_else(|_| href.to_string(), String::from),
+        None => href.to_string(),
+      });
+
+      authors.push(Author { name, url });
+    }
+
+    if authors.is_empty()
+      && let Some(byline) = &context.metadata.byline
+    {
+      authors = Self::split_byline(byline)
+        .into_iter()
+        .map(|name| Author { name, url: None })
+        .collect();
+    }
+
+    if context.tracing() && !authors.is_empty() {
+      let names = authors
+        .iter()
+        .map(|author| author.name.as_str())
+        .collect::<Vec<_>>();
+
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("extracted authors {names:?}").into()),
+      );
+    }
+
+    context.metadata.authors = authors;
+
+    Ok(())
+  }
+}
+
+impl ExtractAuthors {
+  fn split_byline(byline: &str) -> Vec<String> {
+    let byline = BYLINE_TRAILER.replace(byline.trim(), "");
+
+    let byline = Self::strip_prefix(&byline);
+
+    let mut names = Vec::new();
+
+    for name in BYLINE_SEPARATOR.split(byline) {
+      let name = NORMALIZE_WHITESPACE.replace_all(name.trim(), " ");
+
+      if name.is_empty()
+        || name.chars().any(|c| c.is_ascii_digit())
+        || name.split_whitespace().count() > MAX_NAME_WORDS
+        || BYLINE_ROLE.is_match(&name)
+        || names.contains(&name)
+      {
+        continue;
+      }
+
+      names.push(name);
+    }
+
+    names.into_iter().map(String::from).collect()
+  }
+
+  fn strip_prefix(byline: &str) -> &str {
+    if let Some(prefix) = BYLINE_PREFIX.find(byline) {
+      return &byline[prefix.end()..];
+    }
+
+    if let Some(prefix) = BYLINE_PARTICLE_PREFIX.find(byline) {
+      let rest = &byline[prefix.end()..];
+
+      let words = BYLINE_SEPARATOR
+        .split(rest)
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .count();
+
+      if words >= MIN_PARTICLE_NAME_WORDS {
+        return rest;
+      }
+    }
+
+    byline
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn author(name: &str, url: Option<&str>) -> Author {
+    Author {
+      name: name.into(),
+      url: url.map(str::to_owned),
+    }
+  }
+
+  #[track_caller]
+  fn split(byline: &str, expected: &[&str]) {
+    assert_eq!(ExtractAuthors::split_byline(byline), expected, "{byline}");
+  }
+
+  #[test]
+  fn splits_bylines() {
+    split(
+      "By Jane Doe and John Smith | Staff",
+      &["Jane Doe", "John Smith"],
+    );
+    split("Jane Doe, Staff Writer", &["Jane Doe"]);
+    split("by Jane Doe on March 3, 2021", &["Jane Doe"]);
+    split(
+      "Written by: Jane Doe & John Smith",
+      &["Jane Doe", "John Smith"],
+    );
+    split(
+      "Par Marie Dupont et Jean Martin, le 3 mars 2021",
+      &["Marie Dupont", "Jean Martin"],
+    );
+    split(
+      "Von Max Mustermann und Erika Musterfrau",
+      &["Max Mustermann", "Erika Musterfrau"],
+    );
+    split(
+      "Por Ana García y Luis Pérez - Redacción",
+      &["Ana García", "Luis Pérez"],
+    );
+    split("Jane Doe\n  Updated 3 hours ago", &["Jane Doe"]);
+    split("Byron Smith", &["Byron Smith"]);
+    split("By John E. Smith", &["John E. Smith"]);
+    split("By Mary Y. Jones", &["Mary Y. Jones"]);
+    split(
+      "Door Jan Jansen en Piet de Vries",
+      &["Jan Jansen", "Piet de Vries"],
+    );
+  }
+
+  #[test]
+  fn keeps_surname_particles() {
+    split("Di Maio", &["Di Maio"]);
+    split("Von Hagen", &["Von Hagen"]);
+    split(
+      "Von Hagen und Max Mustermann",
+      &["Von Hagen", "Max Mustermann"],
+    );
+    split("Door Jan de Vries", &["Jan de Vries"]);
+    split("di Luigi Di Maio", &["Luigi Di Maio"]);
+    split("Von: Hagen", &["Hagen"]);
+    split("Av Kari Nordmann", &["Kari Nordmann"]);
+  }
+
+  #[test]
+  fn keeps_json_ld_authors() {
+    Test::new()
+      .stage(ExtractAuthors)
+      .metadata(Metadata {
+        authors: vec![author("foo", None)],
+        byline: Some("bar and baz".into()),
+        ..Metadata::default()
+      })
+      .expected_metadata(Metadata {
+        authors: vec![author("foo", None)],
+        byline: Some("bar and baz".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn uses_rel_author_links_in_article() {
+    Test::new()
+      .stage(ExtractAuthors)
+      .base_url(Some("https://example.com/posts/1"))
+      .metadata(Metadata {
+        byline: Some("By foo and bar".into()),
+        ..Metadata::default()
+      })
+      .document(&format!(
+        r#"<html><body><aside><a rel="author" href="/people/baz">baz</a></aside><article><p>By <a rel="author" href="/people/foo">foo</a> and <a rel="author" href="/people/bar">bar</a></p><p>{TEXT}</p><p>{TEXT}</p><p><a rel="author" href="/people/foo">foo</a></p></article><section><a rel="author" href="/people/qux">qux</a></section></body></html>"#
+      ))
+      .expected_metadata(Metadata {
+        authors: vec![
+          author("foo", Some("https://example.com/people/foo")),
+          author("bar", Some("https://example.com/people/bar")),
+        ],
+        byline: Some("By foo and bar".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn uses_rel_author_links_in_byline_area() {
+    Test::new()
+      .stage(ExtractAuthors)
+      .document(
+        r#"<html><body><header><a rel="author" href="/people/foo">foo</a></header><div class="meta"><span class="byline"><a rel="author" href="/people/bar">bar</a></span></div><aside class="sidebar"><a rel="author" href="/people/baz">baz</a></aside><div id="comments"><a rel="author" href="/people/qux">qux</a></div></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        authors: vec![
+          author("foo", Some("/people/foo")),
+          author("bar", Some("/people/bar")),
+        ],
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn splits_byline_without_links() {
+    Test::new()
+      .stage(ExtractAuthors)
+      .metadata(Metadata {
+        byline: Some("By foo, bar and baz | Staff".into()),
+        ..Metadata::default()
+      })
+      .expected_metadata(Metadata {
+        authors: vec![
+          author("foo", None),
+          author("bar", None),
+          author("baz", None),
+        ],
+        byline: Some("By foo, bar and baz | Staff".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+}
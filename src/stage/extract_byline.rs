@@ -34,7 +34,11 @@ impl Stage for ExtractByline {
 
       let is_byline_candidate = rel.as_ref() == "author"
         || itemprop.contains("author")
-        || BYLINE.is_match(&format!("{class} {id}"));
+        || context
+          .options
+          .patterns
+          .byline
+          .is_match(&format!("{class} {id}"));
 
       if !is_byline_candidate {
         continue;
@@ -60,6 +64,11 @@ impl Stage for ExtractByline {
           |node| node.text().trim().to_string(),
         );
 
+      context.trace(
+        Some(&node),
+        TraceEventKind::Message("selected as byline".into()),
+      );
+
       context.metadata.byline = Some(byline);
 
       break;
@@ -12,6 +12,15 @@ impl Stage for ExtractDir {
         .filter(|dir| !dir.is_empty())
     });
 
+    if context.tracing()
+      && let Some(dir) = &context.dir
+    {
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("extracted dir {dir:?}").into()),
+      );
+    }
+
     Ok(())
   }
 }
@@ -10,22 +10,23 @@ impl Stage for ExtractExcerpt {
       return Ok(());
     }
 
-    context.metadata.excerpt = context
+    let paragraph = context
       .document
       .select("p")
       .nodes()
       .iter()
-      .find_map(|node| {
-        let text = node.text();
+      .find(|node| !node.text().trim().is_empty())
+      .cloned();
 
-        let trimmed = text.trim();
+    if let Some(paragraph) = &paragraph {
+      context.trace(
+        Some(paragraph),
+        TraceEventKind::Message("used as excerpt".into()),
+      );
+    }
 
-        if trimmed.is_empty() {
-          None
-        } else {
-          Some(trimmed.to_string())
-        }
-      });
+    context.metadata.excerpt =
+      paragraph.map(|paragraph| paragraph.text().trim().to_string());
 
     Ok(())
   }
@@ -0,0 +1,108 @@
+use super::*;
+
+/// Images declaring a width or height below this many pixels are not
+/// considered as the fallback lead image.
+const MIN_IMAGE_DIMENSION: u32 = 100;
+
+/// Settles the lead image in `Metadata::image`.
+///
+/// An image found by `ExtractJsonLd` or `ExtractMetaTags` is kept. Without
+/// one, the first `<img>` in `Context::article` with a non-`data:` source and
+/// no declared width or height below `MIN_IMAGE_DIMENSION` is used, so this
+/// stage must run before `CleanAttributes` strips image sizes. Either way, the
+/// URL was already resolved by `ResolveRelativeUrls`.
+pub(crate) struct ExtractImage;
+
+impl Stage for ExtractImage {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    if context.metadata.image.is_none() {
+      context.metadata.image = Self::first_sizeable_image(context);
+    }
+
+    if context.tracing()
+      && let Some(image) = &context.metadata.image
+    {
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("lead image {image:?}").into()),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+impl ExtractImage {
+  fn first_sizeable_image(context: &Context) -> Option<String> {
+    let article = context.article()?;
+
+    Selection::from(article)
+      .select("img[src]")
+      .nodes()
+      .iter()
+      .filter(|image| {
+        ["width", "height"].iter().all(|attribute| {
+          image
+            .attr(attribute)
+            .and_then(|value| {
+              value.trim().trim_end_matches("px").parse::<u32>().ok()
+            })
+            .is_none_or(|dimension| dimension >= MIN_IMAGE_DIMENSION)
+        })
+      })
+      .filter_map(|image| image.attr("src"))
+      .map(|src| src.trim().to_string())
+      .find(|src| !src.is_empty() && !src.starts_with("data:"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn falls_back_to_first_sizeable_image_in_article() {
+    let text =
+      "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(4);
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .stage(ExtractImage)
+      .base_url(Some("https://example.com/posts/1"))
+      .document(&format!(
+        r#"<html><body><aside><img src="outside.jpg"></aside><article><img src="data:image/gif;base64,R0lGOD"><img src="icon.png" width="16" height="16"><img src="hero.jpg" width="800px"><p>{text}</p><p>{text}</p></article></body></html>"#,
+      ))
+      .expected_metadata(Metadata {
+        image: Some("https://example.com/posts/hero.jpg".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn keeps_metadata_image() {
+    Test::new()
+      .stage(ExtractImage)
+      .metadata(Metadata {
+        image: Some("/foo.jpg".into()),
+        ..Metadata::default()
+      })
+      .document(r#"<html><body><img src="bar.jpg"></body></html>"#)
+      .expected_metadata(Metadata {
+        image: Some("/foo.jpg".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn no_image_without_article() {
+    Test::new()
+      .stage(ExtractImage)
+      .document(r#"<html><body><img src="foo.jpg"></body></html>"#)
+      .expected_metadata(Metadata::default())
+      .run();
+  }
+}
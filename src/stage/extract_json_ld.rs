@@ -24,37 +24,63 @@ const ARTICLE_TYPES: &[&str] = &[
   "APIReference",
 ];
 
+/// Nodes carrying an `@id`, across every JSON-LD script on the page.
+type Ids<'a> = HashMap<&'a str, &'a serde_json::Value>;
+
+/// Reads `Metadata` from the schema.org article described by the page's
+/// `application/ld+json` scripts.
+///
+/// References such as `{ "@id": "#author" }` are resolved against nodes from
+/// any script on the page. When several article nodes are present, the one
+/// whose URL matches the page's canonical URL is chosen, then the one whose
+/// headline appears in the page `<title>`, then the first.
 pub(crate) struct ExtractJsonLd;
 
 impl Stage for ExtractJsonLd {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
-    let scripts = context
+    let values = context
       .document
       .select("script[type='application/ld+json']")
       .nodes()
-      .to_vec();
+      .iter()
+      .filter_map(|script| {
+        let text = script.text();
 
-    for script in scripts {
-      let text = script.text();
+        let text = text
+          .trim()
+          .trim_start_matches("<![CDATA[")
+          .trim_end_matches("]]>")
+          .trim();
 
-      let text = text.trim();
+        serde_json::from_str::<serde_json::Value>(text).ok()
+      })
+      .collect::<Vec<_>>();
 
-      let text = text
-        .trim_start_matches("<![CDATA[")
-        .trim_end_matches("]]>")
-        .trim();
+    let mut ids = Ids::new();
 
-      let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
-        continue;
-      };
+    let mut articles = Vec::new();
 
-      let Some(article) = Self::find_article(&value) else {
-        continue;
-      };
+    for value in &values {
+      Self::index_ids(value, &mut ids);
+      Self::find_articles(value, &mut articles);
+    }
 
-      context.metadata = Self::extract_metadata(article);
+    if let Some(article) = Self::select_article(context, &articles, &ids) {
+      if context.tracing() {
+        context.trace(
+          None,
+          TraceEventKind::Message(
+            format!(
+              "selected article {:?} of {}",
+              article.get("@type").unwrap_or(&serde_json::Value::Null),
+              articles.len()
+            )
+            .into(),
+          ),
+        );
+      }
 
-      break;
+      context.metadata = Self::extract_metadata(article, &ids);
     }
 
     Ok(())
@@ -62,8 +88,57 @@ impl Stage for ExtractJsonLd {
 }
 
 impl ExtractJsonLd {
-  fn extract_byline(article: &serde_json::Value) -> Option<String> {
-    let author = article.get("author")?;
+  /// Normalizes `url` for comparison, resolving it against `base` and
+  /// dropping the fragment and any trailing slash.
+  fn comparable_url(base: Option<&Url>, url: &str) -> Option<String> {
+    let url = url.trim();
+
+    let mut url = match base {
+      Some(base) => base.join(url).ok()?,
+      None => Url::parse(url).ok()?,
+    };
+
+    url.set_fragment(None);
+
+    Some(url.as_str().trim_end_matches('/').to_owned())
+  }
+
+  fn extract_authors(article: &serde_json::Value, ids: &Ids) -> Vec<Author> {
+    let authors = match article.get("author") {
+      Some(serde_json::Value::Array(authors)) => authors.iter().collect(),
+      Some(author) => vec![author],
+      None => Vec::new(),
+    };
+
+    authors
+      .into_iter()
+      .filter_map(|author| {
+        let (name, url) = match Self::resolve(author, ids) {
+          serde_json::Value::String(name) => (name.as_str(), None),
+          serde_json::Value::Object(object) => (
+            object.get("name").and_then(|value| value.as_str())?,
+            object
+              .get("url")
+              .and_then(|value| value.as_str())
+              .map(str::trim)
+              .filter(|url| !url.is_empty())
+              .map(str::to_owned),
+          ),
+          _ => return None,
+        };
+
+        let name = name.trim();
+
+        (!name.is_empty()).then(|| Author {
+          name: name.to_owned(),
+          url,
+        })
+      })
+      .collect()
+  }
+
+  fn extract_byline(article: &serde_json::Value, ids: &Ids) -> Option<String> {
+    let author = Self::resolve(article.get("author")?, ids);
 
     if let Some(name) = author.get("name").and_then(|value| value.as_str()) {
       let name = name.trim();
@@ -77,7 +152,9 @@ impl ExtractJsonLd {
       let names = authors
         .iter()
         .filter_map(|author| {
-          author.get("name").and_then(|value| value.as_str())
+          Self::resolve(author, ids)
+            .get("name")
+            .and_then(|value| value.as_str())
         })
         .map(str::trim)
         .filter(|name| !name.is_empty())
@@ -92,10 +169,54 @@ impl ExtractJsonLd {
     None
   }
 
-  fn extract_metadata(article: &serde_json::Value) -> Metadata {
+  fn extract_image(article: &serde_json::Value, ids: &Ids) -> Option<String> {
+    let image = article.get("image")?;
+
+    let image = match image {
+      serde_json::Value::Array(images) => images.first()?,
+      _ => image,
+    };
+
+    let url = match Self::resolve(image, ids) {
+      serde_json::Value::String(url) => url,
+      serde_json::Value::Object(object) => object
+        .get("url")
+        .or_else(|| object.get("contentUrl"))
+        .and_then(|value| value.as_str())?,
+      _ => return None,
+    };
+
+    Some(url.trim())
+      .filter(|url| !url.is_empty())
+      .map(str::to_owned)
+  }
+
+  fn extract_keywords(article: &serde_json::Value) -> Vec<String> {
+    let keywords = match article.get("keywords") {
+      Some(serde_json::Value::String(keywords)) => {
+        keywords.split(',').collect::<Vec<_>>()
+      }
+      Some(serde_json::Value::Array(keywords)) => keywords
+        .iter()
+        .filter_map(serde_json::Value::as_str)
+        .collect(),
+      _ => Vec::new(),
+    };
+
+    keywords
+      .into_iter()
+      .map(str::trim)
+      .filter(|keyword| !keyword.is_empty())
+      .map(str::to_owned)
+      .collect()
+  }
+
+  fn extract_metadata(article: &serde_json::Value, ids: &Ids) -> Metadata {
+    let authors = Self::extract_authors(article, ids);
+
     let title = Self::extract_title(article);
 
-    let byline = Self::extract_byline(article);
+    let byline = Self::extract_byline(article, ids);
 
     let excerpt = article
       .get("description")
@@ -106,12 +227,18 @@ impl ExtractJsonLd {
 
     let site_name = article
       .get("publisher")
-      .and_then(|publish| publish.get("name"))
+      .and_then(|publisher| Self::resolve(publisher, ids).get("name"))
       .and_then(|value| value.as_str())
       .map(str::trim)
       .filter(|s| !s.is_empty())
       .map(str::to_owned);
 
+    let image = Self::extract_image(article, ids);
+
+    let keywords = Self::extract_keywords(article);
+
+    let modified_time = Self::extract_string(article, "dateModified");
+
     let published_time = article
       .get("datePublished")
       .and_then(|value| value.as_str())
@@ -119,15 +246,43 @@ impl ExtractJsonLd {
       .filter(|s| !s.is_empty())
       .map(str::to_owned);
 
+    let section = match article.get("articleSection") {
+      Some(serde_json::Value::Array(sections)) => sections
+        .iter()
+        .filter_map(serde_json::Value::as_str)
+        .map(str::trim)
+        .find(|s| !s.is_empty())
+        .map(str::to_owned),
+      _ => Self::extract_string(article, "articleSection"),
+    };
+
+    let url = Self::extract_string(article, "url");
+
     Metadata {
+      authors,
       byline,
       excerpt,
+      image,
+      keywords,
+      modified_time,
+      published_at: None,
       published_time,
+      section,
       site_name,
       title,
+      url,
     }
   }
 
+  fn extract_string(article: &serde_json::Value, key: &str) -> Option<String> {
+    article
+      .get(key)
+      .and_then(|value| value.as_str())
+      .map(str::trim)
+      .filter(|s| !s.is_empty())
+      .map(str::to_owned)
+  }
+
   fn extract_title(article: &serde_json::Value) -> Option<String> {
     let name = article
       .get("name")
@@ -149,38 +304,77 @@ impl ExtractJsonLd {
     }
   }
 
-  fn find_article(value: &serde_json::Value) -> Option<&serde_json::Value> {
-    let value = if let Some(array) = value.as_array() {
-      array
-        .iter()
-        .find(|item| item.get("@type").is_some_and(Self::is_article_type))?
-    } else {
-      value
+  /// Pushes the schema.org article nodes in `value`, a top-level object,
+  /// array or `@graph`, onto `articles`.
+  fn find_articles<'a>(
+    value: &'a serde_json::Value,
+    articles: &mut Vec<&'a serde_json::Value>,
+  ) {
+    let items = match value {
+      serde_json::Value::Array(items) => items.iter().collect(),
+      _ => vec![value],
     };
 
-    if !value.get("@context").is_some_and(Self::is_schema_org) {
-      return None;
-    }
+    for item in items {
+      if !item.get("@context").is_some_and(Self::is_schema_org) {
+        continue;
+      }
 
-    if let Some(graph) = value.get("@graph").and_then(|graph| graph.as_array())
-      && value.get("@type").is_none()
-    {
-      return graph
-        .iter()
-        .find(|item| item.get("@type").is_some_and(Self::is_article_type));
+      if let Some(graph) = item.get("@graph").and_then(|graph| graph.as_array())
+        && item.get("@type").is_none()
+      {
+        articles.extend(
+          graph.iter().filter(|node| {
+            node.get("@type").is_some_and(Self::is_article_type)
+          }),
+        );
+      } else if item.get("@type").is_some_and(Self::is_article_type) {
+        articles.push(item);
+      }
     }
+  }
 
-    if !value.get("@type").is_some_and(Self::is_article_type) {
-      return None;
-    }
+  /// Records every node carrying an `@id` along with other properties, so
+  /// that references such as `{ "@id": "#author" }` can be resolved.
+  fn index_ids<'a>(
+    value: &'a serde_json::Value,
+    ids: &mut HashMap<&'a str, &'a serde_json::Value>,
+  ) {
+    match value {
+      serde_json::Value::Array(values) => {
+        for value in values {
+          Self::index_ids(value, ids);
+        }
+      }
+      serde_json::Value::Object(object) => {
+        if let Some(id) = object.get("@id").and_then(|id| id.as_str())
+          && object.len() > 1
+        {
+          let entry = ids.entry(id).or_insert(value);
+
+          if Self::property_count(value) > Self::property_count(entry) {
+            *entry = value;
+          }
+        }
 
-    Some(value)
+        for value in object.values() {
+          Self::index_ids(value, ids);
+        }
+      }
+      _ => {}
+    }
   }
 
   fn is_article_type(value: &serde_json::Value) -> bool {
-    value
-      .as_str()
-      .is_some_and(|string| ARTICLE_TYPES.contains(&string))
+    match value {
+      serde_json::Value::String(string) => {
+        ARTICLE_TYPES.contains(&string.as_str())
+      }
+      serde_json::Value::Array(types) => {
+        types.iter().any(Self::is_article_type)
+      }
+      _ => false,
+    }
   }
 
   fn is_schema_org(context: &serde_json::Value) -> bool {
@@ -193,12 +387,128 @@ impl ExtractJsonLd {
       _ => false,
     }
   }
+
+  /// Returns the node `value` refers to when it is a bare reference such as
+  /// `{ "@id": "#author" }` to a node indexed in `ids`, and `value` itself
+  /// otherwise.
+  /// Number of properties of `value` that are not JSON-LD keywords such as
+  /// `@id` or `@type`.
+  fn property_count(value: &serde_json::Value) -> usize {
+    value.as_object().map_or(0, |object| {
+      object.keys().filter(|key| !key.starts_with('@')).count()
+    })
+  }
+
+  fn resolve<'a>(
+    value: &'a serde_json::Value,
+    ids: &Ids<'a>,
+  ) -> &'a serde_json::Value {
+    let Some(object) = value.as_object() else {
+      return value;
+    };
+
+    if !object.keys().all(|key| key.starts_with('@')) {
+      return value;
+    }
+
+    object
+      .get("@id")
+      .and_then(|id| id.as_str())
+      .and_then(|id| ids.get(id).copied())
+      .unwrap_or(value)
+  }
+
+  /// Chooses among several article nodes by the page's canonical URL, then
+  /// by the page `<title>`, falling back to the first.
+  fn select_article<'a>(
+    context: &Context,
+    articles: &[&'a serde_json::Value],
+    ids: &Ids<'a>,
+  ) -> Option<&'a serde_json::Value> {
+    if articles.len() < 2 {
+      return articles.first().copied();
+    }
+
+    let base_url = context.base_url;
+
+    let page_url = context
+      .document
+      .select("link[rel~='canonical'][href]")
+      .attr("href")
+      .or_else(|| {
+        context
+          .document
+          .select("meta[property='og:url'][content]")
+          .attr("content")
+      })
+      .map(|url| url.to_string())
+      .or_else(|| base_url.map(Url::to_string))
+      .and_then(|url| Self::comparable_url(base_url, &url));
+
+    if let Some(page_url) = page_url {
+      let article = articles.iter().find(|article| {
+        ["url", "@id", "mainEntityOfPage"]
+          .iter()
+          .filter_map(|key| article.get(key))
+          .map(|value| Self::resolve(value, ids))
+          .filter_map(|value| {
+            value
+              .as_str()
+              .or_else(|| value.get("url").and_then(|url| url.as_str()))
+              .or_else(|| value.get("@id").and_then(|id| id.as_str()))
+          })
+          .any(|url| {
+            Self::comparable_url(base_url, url).as_ref() == Some(&page_url)
+          })
+      });
+
+      if let Some(article) = article {
+        return Some(article);
+      }
+    }
+
+    let title = NORMALIZE_WHITESPACE
+      .replace_all(context.document.select("title").text().trim(), " ")
+      .to_lowercase();
+
+    articles
+      .iter()
+      .find(|article| {
+        ["headline", "name"]
+          .iter()
+          .filter_map(|key| article.get(key).and_then(|value| value.as_str()))
+          .map(|headline| {
+            NORMALIZE_WHITESPACE
+              .replace_all(headline.trim(), " ")
+              .to_lowercase()
+          })
+          .any(|headline| !headline.is_empty() && title.contains(&headline))
+      })
+      .or_else(|| articles.first())
+      .copied()
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn article_type_array() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r#"<html><head><script type="application/ld+json">
+        { "@context": "https://schema.org", "@type": ["WebPage", "BlogPosting"], "name": "foo" }
+        </script></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        title: Some("foo".to_string()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
   #[test]
   fn array_of_objects_picks_article() {
     Test::new()
@@ -218,6 +528,50 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn authors_from_objects_strings_and_references() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r##"<html><head><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@graph": [
+            {
+              "@type": "Article",
+              "author": [
+                { "@type": "Person", "name": "foo", "url": "https://example.com/foo" },
+                "bar",
+                { "@id": "#baz" },
+                { "@id": "#missing" }
+              ]
+            },
+            { "@type": "Person", "@id": "#baz", "name": "baz" }
+          ]
+        }
+        </script></head><body></body></html>"##,
+      )
+      .expected_metadata(Metadata {
+        authors: vec![
+          Author {
+            name: "foo".into(),
+            url: Some("https://example.com/foo".into()),
+          },
+          Author {
+            name: "bar".into(),
+            url: None,
+          },
+          Author {
+            name: "baz".into(),
+            url: None,
+          },
+        ],
+        byline: Some("foo, baz".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
   #[test]
   fn cdata_stripped() {
     Test::new()
@@ -283,6 +637,42 @@ mod tests {
         site_name: Some("baz".to_string()),
         published_time: Some("2024-01-01".to_string()),
         byline: Some("qux".to_string()),
+        authors: vec![Author {
+          name: "qux".to_string(),
+          url: None,
+        }],
+        image: None,
+        keywords: Vec::new(),
+        modified_time: None,
+        published_at: None,
+        section: None,
+        url: None,
+      })
+      .run();
+  }
+
+  #[test]
+  fn extracts_modified_time_keywords_section_and_url() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r#"<html><head><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@type": "NewsArticle",
+          "dateModified": "2024-01-02",
+          "keywords": "foo, bar,,",
+          "articleSection": ["", "baz"],
+          "url": "https://example.com/qux"
+        }
+        </script></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        keywords: vec!["foo".into(), "bar".into()],
+        modified_time: Some("2024-01-02".into()),
+        section: Some("baz".into()),
+        url: Some("https://example.com/qux".into()),
+        ..Metadata::default()
       })
       .run();
   }
@@ -363,6 +753,28 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn image_forms() {
+    for image in [
+      r#""https://example.com/foo.jpg""#,
+      r#"{ "@type": "ImageObject", "url": "https://example.com/foo.jpg" }"#,
+      r#"[{ "contentUrl": "https://example.com/foo.jpg" }, "bar.jpg"]"#,
+    ] {
+      Test::new()
+        .stage(ExtractJsonLd)
+        .document(&format!(
+          r#"<html><head><script type="application/ld+json">
+          {{ "@context": "https://schema.org", "@type": "Article", "image": {image} }}
+          </script></head><body></body></html>"#
+        ))
+        .expected_metadata(Metadata {
+          image: Some("https://example.com/foo.jpg".to_string()),
+          ..Metadata::default()
+        })
+        .run();
+    }
+  }
+
   #[test]
   fn invalid_json_skipped() {
     Test::new()
@@ -380,6 +792,22 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn keywords_array() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r#"<html><head><script type="application/ld+json">
+        { "@context": "https://schema.org", "@type": "Article", "keywords": ["foo", " bar "] }
+        </script></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        keywords: vec!["foo".into(), "bar".into()],
+        ..Metadata::default()
+      })
+      .run();
+  }
+
   #[test]
   fn multiple_authors() {
     Test::new()
@@ -394,9 +822,136 @@ mod tests {
         </script></head><body></body></html>"#,
       )
       .expected_metadata(Metadata {
+        authors: vec![
+          Author {
+            name: "foo".to_string(),
+            url: None,
+          },
+          Author {
+            name: "bar".to_string(),
+            url: None,
+          },
+        ],
         byline: Some("foo, bar".to_string()),
         ..Metadata::default()
       })
       .run();
   }
+
+  #[test]
+  fn references_prefer_full_node_over_stub() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r##"<html><head><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@graph": [
+            {
+              "@type": "NewsArticle",
+              "headline": "foo",
+              "author": { "@id": "#bar", "@type": "Person" }
+            },
+            { "@type": "Person", "@id": "#bar", "name": "bar" }
+          ]
+        }
+        </script></head><body></body></html>"##,
+      )
+      .expected_metadata(Metadata {
+        authors: vec![Author {
+          name: "bar".into(),
+          url: None,
+        }],
+        byline: Some("bar".into()),
+        title: Some("foo".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn references_resolved_across_scripts() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r##"<html><head><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@type": "NewsArticle",
+          "headline": "foo",
+          "author": { "@id": "#bar" },
+          "publisher": { "@id": "#baz" },
+          "image": { "@id": "#image" }
+        }
+        </script><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@graph": [
+            { "@type": "Person", "@id": "#bar", "name": "bar" },
+            { "@type": "Organization", "@id": "#baz", "name": "baz" },
+            { "@type": "ImageObject", "@id": "#image", "url": "https://example.com/foo.jpg" }
+          ]
+        }
+        </script></head><body></body></html>"##,
+      )
+      .expected_metadata(Metadata {
+        authors: vec![Author {
+          name: "bar".into(),
+          url: None,
+        }],
+        byline: Some("bar".into()),
+        image: Some("https://example.com/foo.jpg".into()),
+        site_name: Some("baz".into()),
+        title: Some("foo".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn selects_article_matching_canonical_url() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .base_url(Some("https://example.com/posts/2?utm=foo"))
+      .document(
+        r#"<html><head><link rel="canonical" href="/posts/2/"><title>foo</title><script type="application/ld+json">
+        {
+          "@context": "https://schema.org",
+          "@graph": [
+            { "@type": "NewsArticle", "@id": "https://example.com/posts/1#article", "headline": "foo" },
+            {
+              "@type": "NewsArticle",
+              "@id": "https://example.com/posts/2#article",
+              "headline": "bar",
+              "mainEntityOfPage": { "@id": "https://example.com/posts/2" }
+            }
+          ]
+        }
+        </script></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        title: Some("bar".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn selects_article_matching_title() {
+    Test::new()
+      .stage(ExtractJsonLd)
+      .document(
+        r#"<html><head><title>Bar  Baz | Site</title><script type="application/ld+json">
+        [
+          { "@context": "https://schema.org", "@type": "BlogPosting", "headline": "foo" },
+          { "@context": "https://schema.org", "@type": "BlogPosting", "headline": "bar baz" }
+        ]
+        </script></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        title: Some("bar baz".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
 }
@@ -10,6 +10,15 @@ impl Stage for ExtractLang {
       .map(|lang| lang.trim().to_string())
       .filter(|lang| !lang.is_empty());
 
+    if context.tracing()
+      && let Some(lang) = &context.lang
+    {
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("extracted lang {lang:?}").into()),
+      );
+    }
+
     Ok(())
   }
 }
@@ -13,11 +13,25 @@ const EXCERPT_KEYS: &[&str] = &[
   "twitter:description",
 ];
 
+const IMAGE_KEYS: &[&str] = &[
+  "og:image",
+  "og:image:url",
+  "og:image:secure_url",
+  "twitter:image",
+  "twitter:image:src",
+];
+
+const MODIFIED_TIME_KEYS: &[&str] = &["article:modified_time"];
+
 const PUBLISHED_TIME_KEYS: &[&str] =
   &["article:published_time", "parsely-pub-date"];
 
+const SECTION_KEYS: &[&str] = &["article:section"];
+
 const SITE_NAME_KEYS: &[&str] = &["og:site_name"];
 
+const URL_KEYS: &[&str] = &["og:url"];
+
 const TITLE_KEYS: &[&str] = &[
   "dc:title",
   "dcterm:title",
@@ -35,6 +49,17 @@ impl Stage for ExtractMetaTags {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
     let values = Self::collect_meta_values(context.document);
 
+    if context.tracing() {
+      let mut keys = values.keys().collect::<Vec<_>>();
+
+      keys.sort();
+
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("found meta tags {keys:?}").into()),
+      );
+    }
+
     let article_author = values
       .get("article:author")
       .filter(|value| Url::parse(value).is_err())
@@ -46,15 +71,40 @@ impl Stage for ExtractMetaTags {
       |keys: &[&str]| keys.iter().find_map(|key| values.get(*key).cloned());
 
     context.metadata = Metadata {
+      authors: metadata.authors,
       title: metadata.title.or(extract(TITLE_KEYS)),
       byline: metadata
         .byline
         .or_else(|| extract(BYLINE_KEYS).or(article_author)),
       excerpt: metadata.excerpt.or_else(|| extract(EXCERPT_KEYS)),
+      image: metadata
+        .image
+        .or_else(|| Self::extract_image(context.document)),
       site_name: metadata.site_name.or_else(|| extract(SITE_NAME_KEYS)),
+      published_at: metadata.published_at,
       published_time: metadata
         .published_time
         .or_else(|| extract(PUBLISHED_TIME_KEYS)),
+      modified_time: metadata
+        .modified_time
+        .or_else(|| extract(MODIFIED_TIME_KEYS)),
+      keywords: if metadata.keywords.is_empty() {
+        Self::extract_keywords(context.document, &values)
+      } else {
+        metadata.keywords
+      },
+      section: metadata.section.or_else(|| extract(SECTION_KEYS)),
+      url: metadata
+        .url
+        .or_else(|| {
+          context
+            .document
+            .select("link[rel~='canonical'][href]")
+            .attr("href")
+            .map(|href| href.trim().to_string())
+            .filter(|href| !href.is_empty())
+        })
+        .or_else(|| extract(URL_KEYS)),
     };
 
     Ok(())
@@ -102,6 +152,74 @@ impl ExtractMetaTags {
 
     values
   }
+
+  fn extract_image(document: &dom_query::Document) -> Option<String> {
+    let metas = document.select("meta[content]").nodes().to_vec();
+
+    let content = |key: &str| {
+      metas.iter().find_map(|meta| {
+        let matches = [meta.attr("property"), meta.attr("name")]
+          .into_iter()
+          .flatten()
+          .any(|value| {
+            value
+              .split_whitespace()
+              .any(|token| token.eq_ignore_ascii_case(key))
+          });
+
+        matches
+          .then(|| meta.attr("content"))
+          .flatten()
+          .map(|content| content.trim().to_string())
+          .filter(|content| !content.is_empty())
+      })
+    };
+
+    IMAGE_KEYS.iter().find_map(|key| content(key)).or_else(|| {
+      document
+        .select("link[rel~='image_src'][href]")
+        .attr("href")
+        .map(|href| href.trim().to_string())
+        .filter(|href| !href.is_empty())
+    })
+  }
+
+  fn extract_keywords(
+    document: &dom_query::Document,
+    values: &HashMap<String, String>,
+  ) -> Vec<String> {
+    let tags = document
+      .select("meta[property][content]")
+      .nodes()
+      .iter()
+      .filter(|meta| {
+        meta.attr("property").is_some_and(|property| {
+          property
+            .split_whitespace()
+            .any(|token| token.eq_ignore_ascii_case("article:tag"))
+        })
+      })
+      .filter_map(|meta| meta.attr("content"))
+      .map(|content| content.trim().to_string())
+      .filter(|content| !content.is_empty())
+      .collect::<Vec<_>>();
+
+    if !tags.is_empty() {
+      return tags;
+    }
+
+    values
+      .get("keywords")
+      .map(|keywords| {
+        keywords
+          .split(',')
+          .map(str::trim)
+          .filter(|keyword| !keyword.is_empty())
+          .map(str::to_owned)
+          .collect()
+      })
+      .unwrap_or_default()
+  }
 }
 
 #[cfg(test)]
@@ -313,4 +431,113 @@ mod tests {
       })
       .run();
   }
+
+  #[test]
+  fn og_image_preferred_over_twitter_image() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><meta name="twitter:image" content="foo.jpg"/><meta property="og:image:width" content="1200"/><meta property="og:image" content="bar.jpg"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        image: Some("bar.jpg".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn image_src_link() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><link rel="image_src" href=" foo.jpg "/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        image: Some("foo.jpg".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn article_tags_preferred_over_keywords() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><meta name="keywords" content="foo, bar"/><meta property="article:tag" content="baz"/><meta property="article:tag" content="qux"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        keywords: vec!["baz".into(), "qux".into()],
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn keywords() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><meta name="keywords" content="foo, bar,"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        keywords: vec!["foo".into(), "bar".into()],
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn modified_time_and_section() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><meta property="article:modified_time" content="2024-01-02"/><meta property="article:section" content="foo"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        modified_time: Some("2024-01-02".into()),
+        section: Some("foo".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn canonical_link_preferred_over_og_url() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .document(
+        r#"<html><head><meta property="og:url" content="https://example.com/og"/><link rel="canonical" href="/posts/1"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        url: Some("/posts/1".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn json_ld_metadata_takes_precedence() {
+    Test::new()
+      .stage(ExtractMetaTags)
+      .metadata(Metadata {
+        keywords: vec!["foo".into()],
+        modified_time: Some("2024-01-01".into()),
+        section: Some("bar".into()),
+        url: Some("https://example.com/baz".into()),
+        ..Metadata::default()
+      })
+      .document(
+        r#"<html><head><meta name="keywords" content="qux"/><meta property="article:modified_time" content="2024-01-02"/><meta property="article:section" content="qux"/><meta property="og:url" content="https://example.com/qux"/></head><body></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        keywords: vec!["foo".into()],
+        modified_time: Some("2024-01-01".into()),
+        section: Some("bar".into()),
+        url: Some("https://example.com/baz".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
 }
@@ -31,7 +31,17 @@ impl Stage for ExtractTitle {
       return Ok(());
     }
 
-    context.metadata.title = Self::extract(context.document);
+    context.metadata.title =
+      Self::extract(context.document, &context.options.patterns);
+
+    if context.tracing()
+      && let Some(title) = &context.metadata.title
+    {
+      context.trace(
+        None,
+        TraceEventKind::Message(format!("extracted title {title:?}").into()),
+      );
+    }
 
     Ok(())
   }
@@ -73,7 +83,10 @@ impl ExtractTitle {
     Some(raw[first_colon + 1..].trim().to_string())
   }
 
-  fn extract(document: &dom_query::Document) -> Option<String> {
+  fn extract(
+    document: &dom_query::Document,
+    patterns: &Patterns,
+  ) -> Option<String> {
     let raw_title = document.select("title").first().text();
 
     let raw_title_trimmed = raw_title.trim();
@@ -82,12 +95,12 @@ impl ExtractTitle {
       return None;
     }
 
-    let title = Self::separator_candidate(raw_title_trimmed)
+    let title = Self::separator_candidate(raw_title_trimmed, patterns)
       .or_else(|| Self::colon_candidate(raw_title_trimmed, document))
       .or_else(|| Self::header_candidate(raw_title_trimmed, document))
       .unwrap_or_else(|| raw_title_trimmed.to_string());
 
-    let title = TITLE_NORMALIZE_WHITESPACE
+    let title = NORMALIZE_WHITESPACE
       .replace_all(title.trim(), " ")
       .to_string();
 
@@ -111,12 +124,9 @@ impl ExtractTitle {
     Some(headers.first().text().trim().to_string())
   }
 
-  fn separator_candidate(raw: &str) -> Option<String> {
-    if !TITLE_SEPARATOR.is_match(raw) {
-      return None;
-    }
-
-    let last_sep_start = TITLE_SEPARATOR.find_iter(raw).last().unwrap().start();
+  fn separator_candidate(raw: &str, patterns: &Patterns) -> Option<String> {
+    let last_sep_start =
+      patterns.title_separator.find_iter(raw).last()?.start();
 
     let mut candidate = raw[..last_sep_start].to_string();
 
@@ -124,18 +134,19 @@ impl ExtractTitle {
       candidate = TITLE_LEADING_JUNK.replace(raw, "").trim().to_string();
     }
 
-    candidate = TITLE_NORMALIZE_WHITESPACE
+    candidate = NORMALIZE_WHITESPACE
       .replace_all(candidate.trim(), " ")
       .to_string();
 
     let candidate_words = candidate.split_whitespace().count();
 
-    let raw_words_without_seps = TITLE_SEPARATOR
+    let raw_words_without_seps = patterns
+      .title_separator
       .replace_all(raw, "")
       .split_whitespace()
       .count();
 
-    let had_hierarchical = TITLE_HIERARCHICAL_SEPARATOR.is_match(raw);
+    let had_hierarchical = patterns.title_hierarchical_separator.is_match(raw);
 
     let too_short = candidate_words <= MAX_SHORT_TITLE_WORDS;
 
@@ -341,4 +352,22 @@ mod tests {
       })
       .run();
   }
+
+  #[test]
+  fn separator_uses_extended_pattern() {
+    Test::new()
+      .stage(ExtractTitle)
+      .options(
+        ReadabilityOptions::builder()
+          .extend_pattern(Heuristic::TitleSeparator, r"\s::\s")
+          .unwrap()
+          .build(),
+      )
+      .document("<html><head><title>foo bar baz qux quux :: site</title></head><body></body></html>")
+      .expected_metadata(Metadata {
+        title: Some("foo bar baz qux quux".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
 }
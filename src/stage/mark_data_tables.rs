@@ -0,0 +1,326 @@
+use super::*;
+
+/// Descendants that only appear in tables holding tabular data.
+const DATA_TABLE_DESCENDANTS: &str = "col, colgroup, tfoot, thead, th";
+
+/// Tables with more cells than this are considered data tables.
+const MIN_DATA_TABLE_CELLS: usize = 10;
+
+/// Tables with more columns than this are considered data tables.
+const MIN_DATA_TABLE_COLUMNS: usize = 4;
+
+/// Tables with at least this many rows are considered data tables.
+const MIN_DATA_TABLE_ROWS: usize = 10;
+
+/// Classifies every `<table>` in `Context::article` as either a data table or
+/// a layout table.
+///
+/// Data tables are recorded in `Context::data_tables` so later cleaning stages
+/// keep them. A table is a data table unless it has `role="presentation"` or
+/// `datatable="0"`; otherwise a `summary`, a non-empty `<caption>`, or any of
+/// `<col>`, `<colgroup>`, `<tfoot>`, `<thead>`, or `<th>` marks it as one.
+/// Tables containing other tables, or with a single row or column, are layout
+/// tables. The remaining tables are data tables when they are large enough.
+///
+/// Every table is classified before any is changed, so nested tables still
+/// count against their ancestors. Layout tables are then flattened, innermost
+/// first: the table and its cells are renamed to `<div>`, and row groups and
+/// rows are unwrapped.
+pub(crate) struct MarkDataTables;
+
+impl Stage for MarkDataTables {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    let Some(article) = context
+      .article
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    let tables = Selection::from(article).select("table").nodes().to_vec();
+
+    let mut layout_tables = Vec::new();
+
+    for table in tables {
+      if Self::is_data_table(&table) {
+        context.trace(
+          Some(&table),
+          TraceEventKind::Message("marked as data table".into()),
+        );
+
+        context.data_tables.insert(table.id);
+      } else {
+        layout_tables.push(table);
+      }
+    }
+
+    for table in layout_tables.iter().rev() {
+      context.trace(
+        Some(table),
+        TraceEventKind::Message("flattened layout table".into()),
+      );
+
+      Self::flatten(table);
+    }
+
+    Ok(())
+  }
+}
+
+impl MarkDataTables {
+  fn flatten(table: &NodeRef) {
+    for child in table.element_children() {
+      if child.is("thead, tbody, tfoot") {
+        for row in child.element_children() {
+          Self::flatten_row(&row);
+        }
+
+        Self::unwrap(&child);
+      } else {
+        Self::flatten_row(&child);
+      }
+    }
+
+    table.rename("div");
+  }
+
+  fn flatten_row(row: &NodeRef) {
+    if !row.is("tr") {
+      return;
+    }
+
+    for cell in row.element_children() {
+      if cell.is("td, th") {
+        cell.rename("div");
+      }
+    }
+
+    Self::unwrap(row);
+  }
+
+  fn is_data_table(table: &NodeRef) -> bool {
+    if table.attr("role").as_deref() == Some("presentation")
+      || table.attr("datatable").as_deref() == Some("0")
+    {
+      return false;
+    }
+
+    if table
+      .attr("summary")
+      .is_some_and(|summary| !summary.is_empty())
+    {
+      return true;
+    }
+
+    let selection = Selection::from(table.clone());
+
+    if selection
+      .select("caption")
+      .nodes()
+      .first()
+      .is_some_and(|caption| caption.first_child().is_some())
+    {
+      return true;
+    }
+
+    if selection.select(DATA_TABLE_DESCENDANTS).exists() {
+      return true;
+    }
+
+    if selection.select("table").exists() {
+      return false;
+    }
+
+    let (rows, columns) = Self::size(table);
+
+    if rows == 1 || columns == 1 {
+      return false;
+    }
+
+    rows >= MIN_DATA_TABLE_ROWS
+      || columns > MIN_DATA_TABLE_COLUMNS
+      || rows * columns > MIN_DATA_TABLE_CELLS
+  }
+
+  fn size(table: &NodeRef) -> (usize, usize) {
+    let span = |node: &NodeRef, attribute: &str| {
+      node
+        .attr(attribute)
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|span| *span > 0)
+        .unwrap_or(1)
+    };
+
+    let mut rows = 0;
+    let mut columns = 0;
+
+    for row in Selection::from(table.clone()).select("tr").nodes() {
+      rows += span(row, "rowspan");
+
+      columns = columns.max(
+        Selection::from(row.clone())
+          .select("td")
+          .nodes()
+          .iter()
+          .map(|cell| span(cell, "colspan"))
+          .sum(),
+      );
+    }
+
+    (rows, columns)
+  }
+
+  fn unwrap(node: &NodeRef) {
+    if let Some(first_child) = node.first_child() {
+      node.insert_siblings_before(&first_child);
+    }
+
+    node.remove_from_parent();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn grid(rows: usize, columns: usize) -> String {
+    let row = format!("<tr>{}</tr>", "<td>1</td>".repeat(columns));
+    format!("<table><tbody>{}</tbody></table>", row.repeat(rows))
+  }
+
+  #[test]
+  fn flattens_layout_table() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article("<table><tr><td>foo</td><td>bar</td></tr></table>")
+      .expected_article("<div><div>foo</div><div>bar</div></div>")
+      .run();
+  }
+
+  #[test]
+  fn flattens_nested_layout_tables() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(
+        "<table><tr><td><table><tr><td>foo</td></tr></table></td></tr></table>",
+      )
+      .expected_article("<div><div><div><div>foo</div></div></div></div>")
+      .run();
+  }
+
+  #[test]
+  fn flattens_outer_table_around_nested_layout_table() {
+    let row = "<tr><td>1</td><td>1</td></tr>";
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(&format!(
+        "<table><tr><td><table><tr><td>foo</td><td>bar</td></tr></table></td><td>1</td></tr>{}</table>",
+        row.repeat(5)
+      ))
+      .expected_article(&format!(
+        "<div><div><div><div>foo</div><div>bar</div></div></div><div>1</div>{}</div>",
+        "<div>1</div><div>1</div>".repeat(5)
+      ))
+      .run();
+  }
+
+  #[test]
+  fn flattens_presentation_table_with_headers() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(r#"<table role="presentation"><tr><th>foo</th></tr></table>"#)
+      .expected_article(r#"<div role="presentation"><div>foo</div></div>"#)
+      .run();
+  }
+
+  #[test]
+  fn flattens_small_table() {
+    let html = grid(2, 2);
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(&html)
+      .expected_article(
+        "<div><div>1</div><div>1</div><div>1</div><div>1</div></div>",
+      )
+      .run();
+  }
+
+  #[test]
+  fn keeps_large_table() {
+    let html = grid(3, 4);
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(&html)
+      .expected_article(&html)
+      .run();
+  }
+
+  #[test]
+  fn keeps_table_with_caption() {
+    let html = "<table><caption>foo</caption><tbody><tr><td>bar</td></tr></tbody></table>";
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(html)
+      .expected_article(html)
+      .run();
+  }
+
+  #[test]
+  fn keeps_table_with_headers() {
+    let html = "<table><tbody><tr><th>foo</th></tr></tbody></table>";
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(html)
+      .expected_article(html)
+      .run();
+  }
+
+  #[test]
+  fn keeps_table_with_summary() {
+    let html =
+      r#"<table summary="foo"><tbody><tr><td>bar</td></tr></tbody></table>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(html)
+      .expected_article(html)
+      .run();
+  }
+
+  #[test]
+  fn protects_data_tables_from_conditional_cleaning() {
+    let html = r#"<table><thead><tr><th>Link</th></tr></thead><tbody><tr><td><a href="/foo">foo</a></td></tr></tbody></table>"#;
+
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(MarkDataTables)
+      .article(html)
+      .stage(CleanConditionally)
+      .expected_article(html)
+      .run();
+  }
+}
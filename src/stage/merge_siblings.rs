@@ -0,0 +1,241 @@
+use super::*;
+
+/// Siblings with these tags are appended as-is; any other tag is renamed to
+/// `<div>` so later cleaning does not mistake it for a form or table cell.
+const ALTER_TO_DIV_EXCEPTIONS: &[&str] =
+  &["DIV", "ARTICLE", "SECTION", "P", "OL", "UL"];
+
+/// Paragraph siblings longer than this are kept when their link density is
+/// below `MAX_PARAGRAPH_LINK_DENSITY`.
+const LONG_PARAGRAPH_LENGTH: usize = 80;
+
+/// Link density a long paragraph sibling must stay below to be kept.
+const MAX_PARAGRAPH_LINK_DENSITY: f64 = 0.25;
+
+/// Lower bound for the score a sibling needs to be kept.
+const MIN_SIBLING_SCORE: f64 = 10.0;
+
+/// Fraction of the top candidate's score used for the sibling threshold and
+/// for the bonus awarded to siblings sharing the top candidate's class.
+const SIBLING_SCORE_RATIO: f64 = 0.2;
+
+/// Collects the top candidate and its qualifying siblings into a fresh `<div>`
+/// that becomes the sole child of `<body>` and is recorded as
+/// `Context::article`.
+///
+/// A sibling of `Context::top_candidate` is kept when:
+/// - its score, plus a bonus when it shares the top candidate's non-empty
+///   `class`, reaches `max(10, top score * 0.2)`, or
+/// - it is a `<p>` longer than `LONG_PARAGRAPH_LENGTH` characters with a link
+///   density below `MAX_PARAGRAPH_LINK_DENSITY`, or
+/// - it is a shorter, non-empty `<p>` without links containing a sentence end.
+pub(crate) struct MergeSiblings;
+
+impl Stage for MergeSiblings {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    let Some(top_candidate) = context
+      .top_candidate
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    let Some(body) = context.document.select("body").nodes().first().cloned()
+    else {
+      return Ok(());
+    };
+
+    let top_score = context
+      .scores
+      .get(&top_candidate.id)
+      .copied()
+      .unwrap_or_default();
+
+    let threshold = MIN_SIBLING_SCORE.max(top_score * SIBLING_SCORE_RATIO);
+
+    let class = top_candidate.attr("class").unwrap_or_default();
+
+    let bias = context.link_density_bias();
+
+    let container = context.document.tree.new_element("div");
+
+    let siblings = top_candidate.parent().map_or_else(
+      || vec![top_candidate.clone()],
+      |parent| parent.element_children(),
+    );
+
+    for sibling in siblings {
+      let keep = sibling.id == top_candidate.id || {
+        let bonus = if !class.is_empty()
+          && sibling.attr("class").unwrap_or_default() == class
+        {
+          top_score * SIBLING_SCORE_RATIO
+        } else {
+          0.0
+        };
+
+        context
+          .scores
+          .get(&sibling.id)
+          .is_some_and(|score| score + bonus >= threshold)
+          || (sibling.is("p") && Self::is_readable_paragraph(&sibling, bias))
+      };
+
+      if !keep {
+        continue;
+      }
+
+      if sibling.id != top_candidate.id {
+        context.trace(
+          Some(&sibling),
+          TraceEventKind::Message("merged into article".into()),
+        );
+      }
+
+      if !ALTER_TO_DIV_EXCEPTIONS.contains(&sibling.tag_name().as_str()) {
+        sibling.rename("div");
+      }
+
+      container.append_child(&sibling);
+    }
+
+    body.remove_children();
+    body.append_child(&container);
+
+    context.article = Some(container.id);
+
+    Ok(())
+  }
+}
+
+impl MergeSiblings {
+  fn is_readable_paragraph(node: &NodeRef, bias: f64) -> bool {
+    let text = node.inner_text();
+
+    let length = text.chars().count();
+
+    let link_density = node.link_density(bias);
+
+    if length > LONG_PARAGRAPH_LENGTH {
+      return link_density < MAX_PARAGRAPH_LINK_DENSITY;
+    }
+
+    length > 0
+      && length < LONG_PARAGRAPH_LENGTH
+      && link_density == 0.0
+      && (text.contains(". ") || text.ends_with('.'))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn appends_high_scoring_sibling() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><div id="more"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><div id="more"><p>{TEXT}</p><p>{TEXT}</p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn appends_long_paragraph_sibling() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><p>{TEXT}</p><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><p>{TEXT}</p><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn appends_short_sentence_sibling() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p>Foo bar.</p></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p>Foo bar.</p></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn skips_link_heavy_paragraph_sibling() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p><a href="/foo">Foo bar.</a></p></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn link_density_bias_keeps_link_heavy_paragraph_sibling() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .options(ReadabilityOptions::builder().link_density_bias(1.0).build())
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p><a href="/foo">Foo bar.</a></p></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p><a href="/foo">Foo bar.</a></p></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn skips_short_paragraph_without_sentence() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><p>Foo bar</p></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn renames_uncommon_sibling_tags_to_div() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .document(&format!(
+        r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><blockquote><p>{TEXT}</p><p>{TEXT}</p></blockquote></body></html>"#
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div><div><p>{TEXT}</p><p>{TEXT}</p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn without_top_candidate_leaves_document_untouched() {
+    Test::new()
+      .stage(MergeSiblings)
+      .document("<html><body><p>foo</p></body></html>")
+      .expected_html("<html><head></head><body><p>foo</p></body></html>")
+      .run();
+  }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+/// Parses `Metadata::published_time` into `Metadata::published_at`.
+///
+/// When the document metadata has no usable date, the `datetime` of a
+/// `<time>` element is parsed instead, leaving `Metadata::published_time`
+/// untouched. A `<time>` element with `itemprop="datePublished"` or a
+/// `pubdate` attribute is preferred. Otherwise the first one in the byline
+/// area is used: inside a `<header>`, or inside an element whose class and id
+/// match `Patterns::byline`.
+pub(crate) struct ParsePublishedTime;
+
+impl Stage for ParsePublishedTime {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    if let Some(published_at) = context
+      .metadata
+      .published_time
+      .as_deref()
+      .and_then(timestamp::parse)
+    {
+      if context.tracing() {
+        context.trace(
+          None,
+          TraceEventKind::Message(
+            format!("parsed published time as {published_at}").into(),
+          ),
+        );
+      }
+
+      context.metadata.published_at = Some(published_at);
+
+      return Ok(());
+    }
+
+    let times = context.document.select("time[datetime]").nodes().to_vec();
+
+    let byline = &context.options.patterns.byline;
+
+    let time = times
+      .iter()
+      .find(|time| {
+        time.has_attr("pubdate")
+          || time
+            .attr("itemprop")
+            .is_some_and(|itemprop| itemprop.contains("datePublished"))
+      })
+      .or_else(|| times.iter().find(|time| time.in_byline_area(byline)));
+
+    context.metadata.published_at = time
+      .and_then(|time| time.attr("datetime"))
+      .and_then(|datetime| timestamp::parse(&datetime));
+
+    if let Some(time) = time
+      && context.metadata.published_at.is_some()
+    {
+      context.trace(
+        Some(time),
+        TraceEventKind::Message("used as published time".into()),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    Some(DateTime::parse_from_rfc3339(value).unwrap())
+  }
+
+  #[test]
+  fn parses_metadata_date() {
+    Test::new()
+      .stage(ParsePublishedTime)
+      .metadata(Metadata {
+        published_time: Some("March 3, 2021".into()),
+        ..Metadata::default()
+      })
+      .document(
+        r#"<html><body><time pubdate datetime="2020-01-01">foo</time></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        published_at: timestamp("2021-03-03T00:00:00Z"),
+        published_time: Some("March 3, 2021".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn prefers_date_published() {
+    Test::new()
+      .stage(ParsePublishedTime)
+      .document(
+        r#"<html><body><div class="byline"><time datetime="2020-01-01">foo</time></div><time itemprop="datePublished" datetime=" 2021-03-03 ">bar</time></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        published_at: timestamp("2021-03-03T00:00:00Z"),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn uses_time_in_byline_area() {
+    Test::new()
+      .stage(ParsePublishedTime)
+      .metadata(Metadata {
+        published_time: Some("yesterday".into()),
+        ..Metadata::default()
+      })
+      .document(
+        r#"<html><body><p><time datetime="2020-01-01">foo</time></p><p class="byline">By bar on <span><time datetime="2021-03-03T10:00:00+01:00">baz</time></span></p></body></html>"#,
+      )
+      .expected_metadata(Metadata {
+        published_at: timestamp("2021-03-03T10:00:00+01:00"),
+        published_time: Some("yesterday".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn ignores_time_outside_byline_area() {
+    Test::new()
+      .stage(ParsePublishedTime)
+      .document(
+        r#"<html><body><p><time datetime="2020-01-01">foo</time></p></body></html>"#,
+      )
+      .expected_metadata(Metadata::default())
+      .run();
+  }
+}
@@ -4,12 +4,32 @@ use super::*;
 ///
 /// This stage deletes all `script`, `style`, and `noscript` elements from the
 /// parsed document so later stages do not need to account for executable code,
-/// stylesheet content, or fallback markup.
+/// stylesheet content, or fallback markup. Elements that contain an embed
+/// allowed by `ReadabilityOptions::allowed_video_regex` are kept.
 pub(crate) struct RemoveDisallowedNodes;
 
 impl Stage for RemoveDisallowedNodes {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
-    context.document.select("script, style, noscript").remove();
+    let nodes = context
+      .document
+      .select("script, style, noscript")
+      .nodes()
+      .to_vec();
+
+    for node in nodes {
+      if node.contains_allowed_video(&context.options().allowed_video_regex) {
+        continue;
+      }
+
+      context.trace(
+        Some(&node),
+        TraceEventKind::Removed {
+          reason: "disallowed tag",
+        },
+      );
+
+      node.remove_from_parent();
+    }
 
     Ok(())
   }
@@ -39,6 +59,21 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn keeps_noscript_with_allowed_video() {
+    let noscript = r#"<noscript><iframe src="https://www.youtube.com/embed/foo"></iframe></noscript>"#;
+
+    Test::new()
+      .stage(RemoveDisallowedNodes)
+      .document(&format!(
+        "<html><body>{noscript}<noscript>Enable JS</noscript></body></html>"
+      ))
+      .expected_html(&format!(
+        "<html><head></head><body>{noscript}</body></html>"
+      ))
+      .run();
+  }
+
   #[test]
   fn removes_noscript_tags() {
     Test::new()
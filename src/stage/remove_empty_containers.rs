@@ -30,6 +30,13 @@ impl Stage for RemoveEmptyContainers {
       }
 
       if Self::is_without_content(&node) {
+        context.trace(
+          Some(&node),
+          TraceEventKind::Removed {
+            reason: "empty container",
+          },
+        );
+
         node.remove_from_parent();
       }
     }
@@ -9,6 +9,9 @@ use super::*;
 /// - it carries `aria-hidden="true"` and its `class` does not contain
 ///   `"fallback-image"` (Wikimedia math images use this class)
 /// - it carries both `aria-modal="true"` and `role="dialog"`
+///
+/// Nodes that are or contain an embed allowed by
+/// `ReadabilityOptions::allowed_video_regex` are kept even when hidden.
 pub(crate) struct RemoveHiddenNodes;
 
 impl Stage for RemoveHiddenNodes {
@@ -20,7 +23,11 @@ impl Stage for RemoveHiddenNodes {
         continue;
       }
 
-      if Self::is_hidden(&node) {
+      if Self::is_hidden(&node)
+        && !node.contains_allowed_video(&context.options().allowed_video_regex)
+      {
+        context
+          .trace(Some(&node), TraceEventKind::Removed { reason: "hidden" });
         node.remove_from_parent();
       }
     }
@@ -30,7 +37,7 @@ impl Stage for RemoveHiddenNodes {
 }
 
 impl RemoveHiddenNodes {
-  fn is_hidden(node: &NodeRef) -> bool {
+  pub(crate) fn is_hidden(node: &NodeRef) -> bool {
     if node.attr("hidden").is_some() {
       return true;
     }
@@ -193,6 +200,19 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn keeps_hidden_containers_of_allowed_videos() {
+    Test::new()
+      .stage(RemoveHiddenNodes)
+      .document(
+        r#"<html><body><div style="display: none"><iframe src="https://www.youtube.com/embed/foo"></iframe></div><div hidden><iframe src="https://ads.example.com/"></iframe></div></body></html>"#,
+      )
+      .expected_html(
+        r#"<html><head></head><body><div style="display: none"><iframe src="https://www.youtube.com/embed/foo"></iframe></div></body></html>"#,
+      )
+      .run();
+  }
+
   #[test]
   fn removes_children_with_parent() {
     Test::new()
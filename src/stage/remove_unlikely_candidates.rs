@@ -16,14 +16,22 @@ const UNLIKELY_ROLES: &[&str] = &[
 /// `UNLIKELY_CANDIDATE` pattern and none of the following exceptions apply:
 /// - the `MAYBE_CANDIDATE` pattern also matches
 /// - the node has an ancestor `<table>` or `<code>` element
-/// - the node is `<body>` or `<a>`
 ///
 /// Additionally, nodes whose `role` attribute is in `UNLIKELY_ROLES` are
 /// removed regardless of class or id.
+///
+/// `<body>`, `<a>`, and nodes that are or contain an embed allowed by
+/// `ReadabilityOptions::allowed_video_regex` are never removed.
+///
+/// Does nothing when `Flag::StripUnlikelys` is inactive.
 pub(crate) struct RemoveUnlikelyCandidates;
 
 impl Stage for RemoveUnlikelyCandidates {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
+    if !context.has_flag(Flag::StripUnlikelys) {
+      return Ok(());
+    }
+
     let nodes = context.document.select("*").nodes().to_vec();
 
     for node in nodes {
@@ -40,40 +48,51 @@ impl Stage for RemoveUnlikelyCandidates {
         continue;
       }
 
-      let role = node.attr("role").unwrap_or_default();
+      let Some(reason) = Self::removal_reason(context, &node) else {
+        continue;
+      };
 
-      if UNLIKELY_ROLES.iter().any(|&r| role.as_ref() == r) {
-        node.remove_from_parent();
+      if node.contains_allowed_video(&context.options().allowed_video_regex) {
         continue;
       }
 
-      let class = node.attr("class").unwrap_or_default();
-      let id = node.attr("id").unwrap_or_default();
-      let match_string = format!("{class} {id}");
+      context.trace(Some(&node), TraceEventKind::Removed { reason });
 
-      if !UNLIKELY_CANDIDATE.is_match(&match_string) {
-        continue;
-      }
+      node.remove_from_parent();
+    }
 
-      if MAYBE_CANDIDATE.is_match(&match_string) {
-        continue;
-      }
+    Ok(())
+  }
+}
 
-      let has_table_or_code_ancestor = node.ancestors(None).iter().any(|a| {
-        a.node_name().is_some_and(|n| {
-          let upper = n.to_uppercase();
-          upper == "TABLE" || upper == "CODE"
-        })
-      });
+impl RemoveUnlikelyCandidates {
+  fn removal_reason(context: &Context, node: &NodeRef) -> Option<&'static str> {
+    let role = node.attr("role").unwrap_or_default();
 
-      if has_table_or_code_ancestor {
-        continue;
-      }
+    if UNLIKELY_ROLES.iter().any(|&r| role.as_ref() == r) {
+      return Some("unlikely role");
+    }
 
-      node.remove_from_parent();
+    let class = node.attr("class").unwrap_or_default();
+    let id = node.attr("id").unwrap_or_default();
+    let match_string = format!("{class} {id}");
+
+    let patterns = &context.options().patterns;
+
+    if !patterns.unlikely_candidate.is_match(&match_string)
+      || patterns.maybe_candidate.is_match(&match_string)
+    {
+      return None;
     }
 
-    Ok(())
+    let has_table_or_code_ancestor = node.ancestors(None).iter().any(|a| {
+      a.node_name().is_some_and(|n| {
+        let upper = n.to_uppercase();
+        upper == "TABLE" || upper == "CODE"
+      })
+    });
+
+    (!has_table_or_code_ancestor).then_some("unlikely class or id")
   }
 }
 
@@ -81,6 +100,32 @@ impl Stage for RemoveUnlikelyCandidates {
 mod tests {
   use super::*;
 
+  #[test]
+  fn keeps_containers_of_allowed_video_embeds() {
+    Test::new()
+      .stage(RemoveUnlikelyCandidates)
+      .document(
+        r#"<html><body><div class="sidebar"><iframe src="https://www.youtube.com/embed/foo"></iframe></div><div role="complementary"><iframe src="https://player.vimeo.com/video/1"></iframe></div><div class="sidebar"><iframe src="https://ads.example.com/"></iframe></div></body></html>"#,
+      )
+      .expected_html(
+        r#"<html><head></head><body><div class="sidebar"><iframe src="https://www.youtube.com/embed/foo"></iframe></div><div role="complementary"><iframe src="https://player.vimeo.com/video/1"></iframe></div></body></html>"#,
+      )
+      .run();
+  }
+
+  #[test]
+  fn keeps_allowed_video_embeds() {
+    Test::new()
+      .stage(RemoveUnlikelyCandidates)
+      .document(
+        r#"<html><body><iframe class="social" src="https://www.youtube.com/embed/foo"></iframe><iframe class="social" src="https://ads.example.com/"></iframe></body></html>"#,
+      )
+      .expected_html(
+        r#"<html><head></head><body><iframe class="social" src="https://www.youtube.com/embed/foo"></iframe></body></html>"#,
+      )
+      .run();
+  }
+
   #[test]
   fn removes_comment_class() {
     Test::new()
@@ -92,6 +137,25 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn keeps_class_dropped_from_replaced_pattern() {
+    Test::new()
+      .stage(RemoveUnlikelyCandidates)
+      .options(
+        ReadabilityOptions::builder()
+          .replace_pattern(Heuristic::UnlikelyCandidate, "(?i)sidebar")
+          .unwrap()
+          .build(),
+      )
+      .document(
+        r#"<html><body><div class="comment">foo</div><div class="sidebar">bar</div></body></html>"#,
+      )
+      .expected_html(
+        r#"<html><head></head><body><div class="comment">foo</div></body></html>"#,
+      )
+      .run();
+  }
+
   #[test]
   fn removes_sidebar_id() {
     Test::new()
@@ -201,6 +265,20 @@ mod tests {
       .run();
   }
 
+  #[test]
+  fn keeps_candidates_without_strip_unlikelys_flag() {
+    Test::new()
+      .stage(RemoveUnlikelyCandidates)
+      .flags(&[Flag::WeightClasses, Flag::CleanConditionally])
+      .document(
+        r#"<html><body><div class="sidebar">foo</div><div role="menu">bar</div></body></html>"#,
+      )
+      .expected_html(
+        r#"<html><head></head><body><div class="sidebar">foo</div><div role="menu">bar</div></body></html>"#,
+      )
+      .run();
+  }
+
   #[test]
   fn removes_children_with_parent() {
     Test::new()
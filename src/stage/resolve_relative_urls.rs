@@ -0,0 +1,260 @@
+use super::*;
+
+/// Media elements whose `src`, `poster`, and `srcset` attributes are resolved.
+const MEDIA_ELEMENTS: &str = "img, picture, figure, video, audio, source";
+
+/// Rewrites URLs in `Context::article` to absolute URLs.
+///
+/// URLs are resolved against the document's `<base href>`, itself resolved
+/// against `Context::base_url`, the URL the document was loaded from. Links
+/// with a `javascript:` URL are replaced by their contents, and fragment-only
+/// links are left alone unless a `<base href>` points them at another page.
+/// The stage does nothing when no base URL is known.
+///
+/// `Metadata::image` and `Metadata::url` are resolved against
+/// `Context::base_url` alone, even without an article.
+pub(crate) struct ResolveRelativeUrls;
+
+impl Stage for ResolveRelativeUrls {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    Self::resolve_metadata(context);
+
+    let Some(article) = context
+      .article
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    let base_href = context.document.select("base[href]").attr("href");
+
+    let Some(base_url) = base_href
+      .and_then(|href| match context.base_url {
+        Some(url) => url.join(&href).ok(),
+        None => Url::parse(&href).ok(),
+      })
+      .or_else(|| context.base_url.cloned())
+    else {
+      return Ok(());
+    };
+
+    let keep_fragments = context.base_url == Some(&base_url);
+
+    let resolve = |url: &str| {
+      if keep_fragments && url.starts_with('#') {
+        return url.to_owned();
+      }
+
+      base_url
+        .join(url.trim())
+        .map_or_else(|_| url.to_owned(), String::from)
+    };
+
+    let selection = Selection::from(article);
+
+    for link in selection.select("a[href]").nodes() {
+      let href = link.attr("href").unwrap_or_default();
+
+      if href.starts_with("javascript:") {
+        context.trace(
+          Some(link),
+          TraceEventKind::Message("replaced javascript link".into()),
+        );
+
+        Self::replace_with_contents(context.document, link);
+      } else {
+        Self::set_resolved(context, link, "href", &resolve(&href));
+      }
+    }
+
+    for media in selection.select(MEDIA_ELEMENTS).nodes() {
+      for attribute in ["src", "poster"] {
+        if let Some(value) = media.attr(attribute) {
+          Self::set_resolved(context, media, attribute, &resolve(&value));
+        }
+      }
+
+      if let Some(srcset) = media.attr("srcset") {
+        let srcset = SRCSET_URL.replace_all(&srcset, |captures: &Captures| {
+          format!(
+            "{}{}{}",
+            resolve(&captures[1]),
+            captures.get(2).map_or("", |descriptor| descriptor.as_str()),
+            &captures[3],
+          )
+        });
+
+        Self::set_resolved(context, media, "srcset", &srcset);
+      }
+    }
+
+    for object in selection.select("object[data]").nodes() {
+      let data = object.attr("data").unwrap_or_default();
+      Self::set_resolved(context, object, "data", &resolve(&data));
+    }
+
+    Ok(())
+  }
+}
+
+impl ResolveRelativeUrls {
+  fn replace_with_contents(document: &dom_query::Document, link: &NodeRef) {
+    let children = link.children();
+
+    if let [child] = children.as_slice()
+      && child.is_text()
+    {
+      link.replace_with(child);
+      return;
+    }
+
+    let span = document.tree.new_element("span");
+
+    if let Some(first_child) = link.first_child() {
+      span.append_children(&first_child);
+    }
+
+    link.replace_with(&span);
+  }
+
+  fn resolve_metadata(context: &mut Context) {
+    let Some(base_url) = context.base_url else {
+      return;
+    };
+
+    let Metadata { image, url, .. } = &mut context.metadata;
+
+    for value in [image, url].into_iter().flatten() {
+      if let Ok(resolved) = base_url.join(value.trim()) {
+        *value = resolved.into();
+      }
+    }
+  }
+
+  fn set_resolved(
+    context: &Context,
+    node: &NodeRef,
+    attribute: &str,
+    value: &str,
+  ) {
+    if node.attr(attribute).as_deref() == Some(value) {
+      return;
+    }
+
+    context.trace(
+      Some(node),
+      TraceEventKind::Message("resolved relative URL".into()),
+    );
+
+    node.set_attr(attribute, value);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn honors_base_href() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/a/b"))
+      .document(&format!(
+        r##"<html><head><base href="/docs/"></head><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p><p><a href="foo">foo</a><a href="#bar">bar</a></p></div></body></html>"##
+      ))
+      .expected_html(&format!(
+        r#"<html><head><base href="/docs/"></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p><p><a href="https://example.com/docs/foo">foo</a><a href="https://example.com/docs/#bar">bar</a></p></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn keeps_fragment_links() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/a/b"))
+      .article(r##"<p><a href="#foo">foo</a></p>"##)
+      .expected_article(r##"<p><a href="#foo">foo</a></p>"##)
+      .run();
+  }
+
+  #[test]
+  fn replaces_javascript_links_with_contents() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/"))
+      .article(
+        r#"<p><a href="javascript:void(0)">foo</a><a href="javascript:void(0)">bar <b>baz</b></a></p>"#,
+      )
+      .expected_article("<p>foo<span>bar <b>baz</b></span></p>")
+      .run();
+  }
+
+  #[test]
+  fn resolves_links() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/a/b"))
+      .article(
+        r#"<p><a href="c">foo</a><a href="/d">bar</a><a href="https://other.com/">baz</a></p>"#,
+      )
+      .expected_article(
+        r#"<p><a href="https://example.com/a/c">foo</a><a href="https://example.com/d">bar</a><a href="https://other.com/">baz</a></p>"#,
+      )
+      .run();
+  }
+
+  #[test]
+  fn resolves_media_sources() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/a/"))
+      .article(
+        r#"<p><img src="foo.png" srcset="foo.png 1x, /bar.png 2x"><video poster="baz.png"></video><object data="qux.swf"></object></p>"#,
+      )
+      .expected_article(
+        r#"<p><img src="https://example.com/a/foo.png" srcset="https://example.com/a/foo.png 1x, https://example.com/bar.png 2x"><video poster="https://example.com/a/baz.png"></video><object data="https://example.com/a/qux.swf"></object></p>"#,
+      )
+      .run();
+  }
+
+  #[test]
+  fn resolves_metadata_urls() {
+    Test::new()
+      .stage(ResolveRelativeUrls)
+      .base_url(Some("https://example.com/posts/1?utm=foo"))
+      .metadata(Metadata {
+        image: Some("/foo.jpg".into()),
+        url: Some("/posts/1".into()),
+        ..Metadata::default()
+      })
+      .document("<html><body></body></html>")
+      .expected_metadata(Metadata {
+        image: Some("https://example.com/foo.jpg".into()),
+        url: Some("https://example.com/posts/1".into()),
+        ..Metadata::default()
+      })
+      .run();
+  }
+
+  #[test]
+  fn without_base_url_leaves_urls_untouched() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(ResolveRelativeUrls)
+      .article(r#"<p><a href="foo">foo</a></p>"#)
+      .expected_article(r#"<p><a href="foo">foo</a></p>"#)
+      .run();
+  }
+}
@@ -9,7 +9,16 @@ pub(crate) struct RewriteFontTags;
 
 impl Stage for RewriteFontTags {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
-    context.document.select("font").rename("span");
+    let fonts = context.document.select("font");
+
+    for font in fonts.nodes() {
+      context.trace(
+        Some(font),
+        TraceEventKind::Message("renamed to span".into()),
+      );
+    }
+
+    fonts.rename("span");
 
     Ok(())
   }
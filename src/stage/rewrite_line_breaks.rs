@@ -1,13 +1,5 @@
 use super::*;
 
-const PHRASING_ELEMENTS: &[&str] = &[
-  "abbr", "audio", "b", "bdo", "br", "button", "cite", "code", "data",
-  "datalist", "dfn", "em", "embed", "i", "img", "input", "kbd", "label",
-  "mark", "math", "meter", "noscript", "object", "output", "progress", "q",
-  "ruby", "samp", "script", "select", "small", "span", "strong", "sub", "sup",
-  "textarea", "time", "var", "wbr",
-];
-
 /// Converts `<br><br>` paragraph separators into explicit paragraph elements.
 ///
 /// The stage scans `br` chains, replaces break runs with `<p>`, and moves
@@ -54,7 +46,7 @@ impl Stage for RewriteLineBreaks {
           break;
         }
 
-        if !Self::is_phrasing_content(&sibling) {
+        if !sibling.is_phrasing_content() {
           break;
         }
 
@@ -62,16 +54,18 @@ impl Stage for RewriteLineBreaks {
         par.append_child(&sibling);
       }
 
-      while par
-        .last_child()
-        .is_some_and(|node| Self::is_whitespace(&node))
-      {
+      while par.last_child().is_some_and(|node| node.is_whitespace()) {
         par.last_child().unwrap().remove_from_parent();
       }
 
       if par.parent().is_some_and(|parent| parent.is("p")) {
         par.parent().unwrap().rename("div");
       }
+
+      context.trace(
+        Some(&par),
+        TraceEventKind::Message("replaced line breaks with paragraph".into()),
+      );
     }
 
     Ok(())
@@ -79,26 +73,6 @@ impl Stage for RewriteLineBreaks {
 }
 
 impl RewriteLineBreaks {
-  fn is_phrasing_content(node: &NodeRef) -> bool {
-    if node.is_text() {
-      return true;
-    }
-
-    let Some(name) = node.node_name() else {
-      return false;
-    };
-
-    let name_lower = name.to_ascii_lowercase();
-
-    PHRASING_ELEMENTS.contains(&name_lower.as_ref())
-      || matches!(name_lower.as_ref(), "a" | "del" | "ins")
-        && node.children().iter().all(Self::is_phrasing_content)
-  }
-
-  fn is_whitespace(node: &NodeRef) -> bool {
-    (node.is_text() && node.text().trim().is_empty()) || node.is("br")
-  }
-
   fn next_significant<'a>(start: Option<&NodeRef<'a>>) -> Option<NodeRef<'a>> {
     iter::successors(start.cloned(), NodeRef::next_sibling).find(|node| {
       node.is_element() || (node.is_text() && !node.text().trim().is_empty())
@@ -0,0 +1,470 @@
+use super::*;
+
+/// Characters counted as commas when scoring paragraph text.
+const COMMAS: &[char] = &[
+  '\u{002C}', '\u{060C}', '\u{FE50}', '\u{FE10}', '\u{FE11}', '\u{2E41}',
+  '\u{2E34}', '\u{2E32}', '\u{FF0C}',
+];
+
+/// Elements that keep a `<div>` from being rewritten into a paragraph.
+const DIV_TO_P_ELEMENTS: &[&str] = &[
+  "BLOCKQUOTE",
+  "DL",
+  "DIV",
+  "IMG",
+  "OL",
+  "P",
+  "PRE",
+  "TABLE",
+  "UL",
+];
+
+/// Number of ancestors a paragraph's score is propagated to.
+const MAX_ANCESTOR_DEPTH: usize = 5;
+
+/// A `<div>` wrapping a single `<p>` is unwrapped when its link density is
+/// below this value.
+const MAX_SINGLE_PARAGRAPH_LINK_DENSITY: f64 = 0.25;
+
+/// Elements with less text than this do not contribute to scoring.
+const MIN_PARAGRAPH_LENGTH: usize = 25;
+
+/// Number of near-top candidates that must share an ancestor before that
+/// ancestor replaces the top candidate.
+const MIN_TOP_CANDIDATES: usize = 3;
+
+/// Candidates scoring at least this fraction of the top candidate are
+/// considered alternatives when looking for a shared ancestor.
+const MIN_TOP_CANDIDATE_RATIO: f64 = 0.75;
+
+/// Tags whose text contributes to the score of their ancestors.
+const TAGS_TO_SCORE: &[&str] =
+  &["SECTION", "H2", "H3", "H4", "H5", "H6", "P", "TD", "PRE"];
+
+/// Scores the document and records the node most likely to hold the article
+/// as `Context::top_candidate`.
+///
+/// Before scoring, `<div>` elements are normalized: runs of phrasing content
+/// are wrapped in `<p>`, a `<div>` holding a single low link density `<p>` is
+/// replaced by that paragraph, and a `<div>` without block-level descendants is
+/// renamed to `<p>`.
+///
+/// Every element in `TAGS_TO_SCORE` with at least `MIN_PARAGRAPH_LENGTH`
+/// characters of text contributes `1 + commas + 1 + min(length / 100, 3)`
+/// points to up to `MAX_ANCESTOR_DEPTH` ancestors: the parent receives the full
+/// amount, the grandparent half, and further ancestors a third of the amount
+/// divided by their depth. Each ancestor starts from a tag-based score plus,
+/// when `Flag::WeightClasses` is active, its class weight. Candidate scores are
+/// then scaled by `1 - link density`.
+///
+/// The best of `ReadabilityOptions::n_top_candidates` candidates wins, but is
+/// replaced by an ancestor when enough close runner-ups share it, when the
+/// ancestor scores higher, or when the candidate is an only child. If no
+/// candidate exists, the contents of `<body>` are wrapped in a new `<div>`.
+///
+/// Every contribution and every link density rescale is traced as a
+/// `TraceEventKind::Score` event on the candidate it changed.
+pub(crate) struct ScoreCandidates;
+
+impl Stage for ScoreCandidates {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    let Some(body) = context.document.select("body").nodes().first().cloned()
+    else {
+      return Ok(());
+    };
+
+    let bias = context.link_density_bias();
+
+    Self::normalize_divs(context.document, &body, bias);
+
+    let patterns = context
+      .has_flag(Flag::WeightClasses)
+      .then_some(&context.options().patterns);
+
+    let mut scores = HashMap::new();
+
+    let mut candidates = Vec::new();
+
+    for element in body.element_descendants() {
+      if TAGS_TO_SCORE.contains(&element.tag_name().as_str()) {
+        Self::score_element(
+          context,
+          &element,
+          &mut scores,
+          &mut candidates,
+          patterns,
+        );
+      }
+    }
+
+    for candidate in &candidates {
+      if let Some(score) = scores.get_mut(&candidate.id) {
+        let from = *score;
+
+        *score *= 1.0 - candidate.link_density(bias);
+
+        context
+          .trace(Some(candidate), TraceEventKind::Score { from, to: *score });
+      }
+    }
+
+    let top_candidate = Self::select_top_candidate(
+      context.document,
+      &body,
+      &candidates,
+      &mut scores,
+      context.options().n_top_candidates,
+      patterns,
+    );
+
+    context.trace(
+      Some(&top_candidate),
+      TraceEventKind::Message("selected as top candidate".into()),
+    );
+
+    context.top_candidate = Some(top_candidate.id);
+    context.scores = scores;
+
+    Ok(())
+  }
+}
+
+impl ScoreCandidates {
+  fn has_child_block_element(node: &NodeRef) -> bool {
+    node.children().iter().any(|child| {
+      DIV_TO_P_ELEMENTS.contains(&child.tag_name().as_str())
+        || Self::has_child_block_element(child)
+    })
+  }
+
+  fn has_single_paragraph(node: &NodeRef) -> bool {
+    let children = node.element_children();
+
+    if children.len() != 1 || !children[0].is("p") {
+      return false;
+    }
+
+    !node
+      .children()
+      .iter()
+      .any(|child| child.is_text() && !child.text().trim().is_empty())
+  }
+
+  fn initial_score(node: &NodeRef, patterns: Option<&Patterns>) -> f64 {
+    let score = match node.tag_name().as_str() {
+      "DIV" => 5.0,
+      "PRE" | "TD" | "BLOCKQUOTE" => 3.0,
+      "ADDRESS" | "OL" | "UL" | "DL" | "DD" | "DT" | "LI" | "FORM" => -3.0,
+      "H1" | "H2" | "H3" | "H4" | "H5" | "H6" | "TH" => -5.0,
+      _ => 0.0,
+    };
+
+    score + patterns.map_or(0.0, |patterns| node.class_weight(patterns))
+  }
+
+  fn normalize_divs(document: &dom_query::Document, body: &NodeRef, bias: f64) {
+    for div in Selection::from(body.clone()).select("div").nodes().to_vec() {
+      Self::wrap_phrasing_content(document, &div);
+
+      if Self::has_single_paragraph(&div)
+        && div.link_density(bias) < MAX_SINGLE_PARAGRAPH_LINK_DENSITY
+      {
+        if let Some(paragraph) = div.first_element_child() {
+          div.replace_with(&paragraph);
+        }
+      } else if !Self::has_child_block_element(&div) {
+        div.rename("p");
+      }
+    }
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  fn score_element<'a>(
+    context: &Context,
+    element: &NodeRef<'a>,
+    scores: &mut HashMap<NodeId, f64>,
+    candidates: &mut Vec<NodeRef<'a>>,
+    patterns: Option<&Patterns>,
+  ) {
+    if !element.parent().is_some_and(|parent| parent.is_element()) {
+      return;
+    }
+
+    let text = element.inner_text();
+
+    let length = text.chars().count();
+
+    if length < MIN_PARAGRAPH_LENGTH {
+      return;
+    }
+
+    let commas = text.chars().filter(|c| COMMAS.contains(c)).count();
+
+    let score = (2 + commas + (length / 100).min(3)) as f64;
+
+    for (level, ancestor) in
+      (0u32..).zip(element.ancestors(Some(MAX_ANCESTOR_DEPTH)))
+    {
+      if !ancestor.is_element()
+        || !ancestor.parent().is_some_and(|parent| parent.is_element())
+      {
+        continue;
+      }
+
+      let entry = scores.entry(ancestor.id).or_insert_with(|| {
+        candidates.push(ancestor.clone());
+        Self::initial_score(&ancestor, patterns)
+      });
+
+      let from = *entry;
+
+      *entry += score
+        / match level {
+          0 => 1.0,
+          1 => 2.0,
+          level => f64::from(level) * 3.0,
+        };
+
+      context
+        .trace(Some(&ancestor), TraceEventKind::Score { from, to: *entry });
+    }
+  }
+
+  fn select_top_candidate<'a>(
+    document: &'a dom_query::Document,
+    body: &NodeRef<'a>,
+    candidates: &[NodeRef<'a>],
+    scores: &mut HashMap<NodeId, f64>,
+    n_top_candidates: usize,
+    patterns: Option<&Patterns>,
+  ) -> NodeRef<'a> {
+    let mut top_candidates = candidates.to_vec();
+
+    top_candidates.sort_by(|a, b| scores[&b.id].total_cmp(&scores[&a.id]));
+
+    top_candidates.truncate(n_top_candidates);
+
+    let Some(mut top_candidate) = top_candidates
+      .first()
+      .filter(|node| !node.is("body"))
+      .cloned()
+    else {
+      let container = document.tree.new_element("div");
+
+      while let Some(child) = body.first_child() {
+        container.append_child(&child);
+      }
+
+      body.append_child(&container);
+
+      scores.insert(container.id, Self::initial_score(&container, patterns));
+
+      return container;
+    };
+
+    let top_score = scores[&top_candidate.id];
+
+    let alternative_ancestors = top_candidates[1..]
+      .iter()
+      .filter(|node| scores[&node.id] / top_score >= MIN_TOP_CANDIDATE_RATIO)
+      .map(|node| {
+        node
+          .ancestors(None)
+          .iter()
+          .map(|ancestor| ancestor.id)
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+
+    let within_body = |node: &NodeRef| {
+      node.is_element() && !node.is("body") && !node.is("html")
+    };
+
+    if alternative_ancestors.len() >= MIN_TOP_CANDIDATES {
+      let mut parent = top_candidate.parent();
+
+      while let Some(node) = parent.filter(within_body) {
+        let lists = alternative_ancestors
+          .iter()
+          .filter(|ancestors| ancestors.contains(&node.id))
+          .count();
+
+        if lists >= MIN_TOP_CANDIDATES {
+          top_candidate = node;
+          break;
+        }
+
+        parent = node.parent();
+      }
+    }
+
+    let mut last_score = *scores
+      .entry(top_candidate.id)
+      .or_insert_with(|| Self::initial_score(&top_candidate, patterns));
+
+    let threshold = last_score / 3.0;
+
+    let mut parent = top_candidate.parent();
+
+    while let Some(node) = parent.filter(within_body) {
+      parent = node.parent();
+
+      let Some(&score) = scores.get(&node.id) else {
+        continue;
+      };
+
+      if score < threshold {
+        break;
+      }
+
+      if score > last_score {
+        top_candidate = node;
+        break;
+      }
+
+      last_score = score;
+    }
+
+    while let Some(parent) = top_candidate
+      .parent()
+      .filter(|node| within_body(node) && node.element_children().len() == 1)
+    {
+      top_candidate = parent;
+    }
+
+    scores
+      .entry(top_candidate.id)
+      .or_insert_with(|| Self::initial_score(&top_candidate, patterns));
+
+    top_candidate
+  }
+
+  fn wrap_phrasing_content(document: &dom_query::Document, div: &NodeRef) {
+    let mut paragraph: Option<NodeRef> = None;
+
+    let mut next = div.first_child();
+
+    while let Some(child) = next {
+      next = child.next_sibling();
+
+      if child.is_phrasing_content() {
+        if let Some(paragraph) = &paragraph {
+          paragraph.append_child(&child);
+        } else if !child.is_whitespace() {
+          let new_paragraph = document.tree.new_element("p");
+          child.replace_with(&new_paragraph);
+          new_paragraph.append_child(&child);
+          paragraph = Some(new_paragraph);
+        }
+      } else if let Some(paragraph) = paragraph.take() {
+        while let Some(last) =
+          paragraph.last_child().filter(NodeRefExt::is_whitespace)
+        {
+          last.remove_from_parent();
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn selects_container_with_most_content() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r#"<html><body><div id="nav"><a href="/">home</a></div><div id="main"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ))
+      .expected_top_candidate("#main")
+      .run();
+  }
+
+  #[test]
+  fn class_weight_penalizes_candidates() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r#"<html><body><div class="sidebar"><p>{TEXT}</p><p>{TEXT}</p><p>{TEXT}</p></div><div class="story"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ))
+      .expected_top_candidate(".story")
+      .run();
+  }
+
+  #[test]
+  fn ignores_class_weight_without_weight_classes_flag() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .flags(&[Flag::StripUnlikelys, Flag::CleanConditionally])
+      .document(&format!(
+        r#"<html><body><div class="sidebar"><p>{TEXT}</p><p>{TEXT}</p><p>{TEXT}</p></div><div class="story"><p>{TEXT}</p><p>{TEXT}</p></div></body></html>"#
+      ))
+      .expected_top_candidate(".sidebar")
+      .run();
+  }
+
+  #[test]
+  fn promotes_only_child_to_parent() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r#"<html><body><div id="outer"><div id="inner"><p>{TEXT}</p><p>{TEXT}</p></div></div><p>foo</p></body></html>"#
+      ))
+      .expected_top_candidate("#outer")
+      .run();
+  }
+
+  #[test]
+  fn converts_div_without_block_children_to_paragraph() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r"<html><body><article><div>{TEXT}</div><div>{TEXT}</div></article></body></html>"
+      ))
+      .expected_html(&format!(
+        r"<html><head></head><body><article><p>{TEXT}</p><p>{TEXT}</p></article></body></html>"
+      ))
+      .expected_top_candidate("article")
+      .run();
+  }
+
+  #[test]
+  fn unwraps_div_with_single_paragraph() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r"<html><body><article><div><p>{TEXT}</p></div><div><p>{TEXT}</p></div></article></body></html>"
+      ))
+      .expected_html(&format!(
+        r"<html><head></head><body><article><p>{TEXT}</p><p>{TEXT}</p></article></body></html>"
+      ))
+      .run();
+  }
+
+  #[test]
+  fn wraps_phrasing_content_in_paragraphs() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document(&format!(
+        r"<html><body><section><div>{TEXT}<ul><li>foo</li></ul><b>{TEXT}</b></div></section></body></html>"
+      ))
+      .expected_html(&format!(
+        r"<html><head></head><body><section><div><p>{TEXT}</p><ul><li>foo</li></ul><p><b>{TEXT}</b></p></div></section></body></html>"
+      ))
+      .run();
+  }
+
+  #[test]
+  fn wraps_body_when_nothing_scores() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .document("<html><body><span>foo</span><span>bar</span></body></html>")
+      .expected_html(
+        "<html><head></head><body><div><span>foo</span><span>bar</span></div></body></html>",
+      )
+      .expected_top_candidate("body > div")
+      .run();
+  }
+}
@@ -1,21 +1,44 @@
 use super::*;
 
+/// Filler text long enough for a paragraph to count towards its container's
+/// score.
+pub(crate) const TEXT: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
 enum Assertion<T> {
   Expect(T),
   Unset,
 }
 
 pub(crate) struct Test {
+  base_url: Option<Url>,
   document: Option<String>,
   expected_dir: Assertion<Option<String>>,
   expected_html: Option<String>,
   expected_lang: Assertion<Option<String>>,
   expected_metadata: Option<Metadata>,
+  expected_top_candidate: Option<String>,
+  flags: Option<Vec<Flag>>,
   metadata: Option<Metadata>,
+  options: ReadabilityOptions,
   stages: Vec<Box<dyn Stage>>,
 }
 
 impl Test {
+  /// Uses a document whose `div#main` holds two paragraphs of `TEXT` followed
+  /// by `content`, so that `ScoreCandidates` picks it as the article.
+  pub(crate) fn article(self, content: &str) -> Self {
+    self.document(&format!(
+      r#"<html><body><div id="main"><p>{TEXT}</p><p>{TEXT}</p>{content}</div></body></html>"#
+    ))
+  }
+
+  pub(crate) fn base_url(self, base_url: Option<&str>) -> Self {
+    Self {
+      base_url: base_url.map(|url| Url::parse(url).unwrap()),
+      ..self
+    }
+  }
+
   pub(crate) fn document(self, html: &str) -> Self {
     Self {
       document: Some(html.to_owned()),
@@ -23,6 +46,14 @@ impl Test {
     }
   }
 
+  /// Expects the document from `Test::article`, with `content` in place of the
+  /// original content and wrapped by `MergeSiblings`.
+  pub(crate) fn expected_article(self, content: &str) -> Self {
+    self.expected_html(&format!(
+      r#"<html><head></head><body><div><div id="main"><p>{TEXT}</p><p>{TEXT}</p>{content}</div></div></body></html>"#
+    ))
+  }
+
   pub(crate) fn expected_dir(self, dir: Option<&str>) -> Self {
     Self {
       expected_dir: Assertion::Expect(dir.map(str::to_owned)),
@@ -51,6 +82,20 @@ impl Test {
     }
   }
 
+  pub(crate) fn expected_top_candidate(self, selector: &str) -> Self {
+    Self {
+      expected_top_candidate: Some(selector.to_owned()),
+      ..self
+    }
+  }
+
+  pub(crate) fn flags(self, flags: &[Flag]) -> Self {
+    Self {
+      flags: Some(flags.to_vec()),
+      ..self
+    }
+  }
+
   pub(crate) fn metadata(self, metadata: Metadata) -> Self {
     Self {
       metadata: Some(metadata),
@@ -60,16 +105,24 @@ impl Test {
 
   pub(crate) fn new() -> Self {
     Self {
+      base_url: None,
       document: None,
       expected_dir: Assertion::Unset,
       expected_html: None,
       expected_lang: Assertion::Unset,
       expected_metadata: None,
+      expected_top_candidate: None,
+      flags: None,
       metadata: None,
+      options: ReadabilityOptions::default(),
       stages: Vec::new(),
     }
   }
 
+  pub(crate) fn options(self, options: ReadabilityOptions) -> Self {
+    Self { options, ..self }
+  }
+
   #[track_caller]
   pub(crate) fn run(mut self) {
     let html = self
@@ -79,20 +132,30 @@ impl Test {
 
     let mut document = dom_query::Document::from(html);
 
-    let options = ReadabilityOptions::default();
+    let (metadata, lang, dir, top_candidate) = {
+      let mut context = Context::new(&mut document, &self.options);
+
+      context.base_url = self.base_url.as_ref();
 
-    let (metadata, lang, dir) = {
-      let mut context = Context::new(&mut document, &options);
+      if let Some(flags) = self.flags {
+        context.flags = flags;
+      }
 
       if let Some(metadata) = self.metadata {
         context.metadata = metadata;
       }
 
       for stage in &mut self.stages {
+        context.stage = stage.name();
         stage.run(&mut context).unwrap();
       }
 
-      (context.metadata, context.lang, context.dir)
+      (
+        context.metadata,
+        context.lang,
+        context.dir,
+        context.top_candidate,
+      )
     };
 
     if let Some(expected) = self.expected_html {
@@ -110,6 +173,17 @@ impl Test {
     if let Assertion::Expect(expected) = self.expected_dir {
       assert_eq!(dir, expected);
     }
+
+    if let Some(selector) = self.expected_top_candidate {
+      assert_eq!(
+        top_candidate,
+        document
+          .select(&selector)
+          .nodes()
+          .first()
+          .map(|node| node.id)
+      );
+    }
   }
 
   pub(crate) fn stage(self, stage: impl Stage + 'static) -> Self {
@@ -12,14 +12,41 @@ impl Stage for UnescapeHtmlEntities {
   fn run(&mut self, context: &mut Context<'_>) -> Result {
     let metadata = mem::take(&mut context.metadata);
 
+    let original = context.tracing().then(|| metadata.clone());
+
     context.metadata = Metadata {
+      authors: metadata
+        .authors
+        .into_iter()
+        .map(|author| Author {
+          name: Self::unescape(&author.name),
+          url: author.url.map(|url| Self::unescape(&url)),
+        })
+        .collect(),
       byline: metadata.byline.map(|s| Self::unescape(&s)),
       excerpt: metadata.excerpt.map(|s| Self::unescape(&s)),
+      image: metadata.image.map(|s| Self::unescape(&s)),
+      keywords: metadata
+        .keywords
+        .iter()
+        .map(|s| Self::unescape(s))
+        .collect(),
+      modified_time: metadata.modified_time.map(|s| Self::unescape(&s)),
+      published_at: metadata.published_at,
       published_time: metadata.published_time.map(|s| Self::unescape(&s)),
+      section: metadata.section.map(|s| Self::unescape(&s)),
       site_name: metadata.site_name.map(|s| Self::unescape(&s)),
       title: metadata.title.map(|s| Self::unescape(&s)),
+      url: metadata.url.map(|s| Self::unescape(&s)),
     };
 
+    if original.is_some_and(|original| original != context.metadata) {
+      context.trace(
+        None,
+        TraceEventKind::Message("unescaped metadata entities".into()),
+      );
+    }
+
     Ok(())
   }
 }
@@ -132,6 +159,16 @@ mod tests {
         excerpt: Some("foo &amp; bar".into()),
         site_name: Some("foo &amp; bar".into()),
         published_time: Some("foo &amp; bar".into()),
+        image: Some("foo.jpg?a=1&amp;b=2".into()),
+        keywords: vec!["foo &amp; bar".into()],
+        modified_time: Some("foo &amp; bar".into()),
+        published_at: None,
+        section: Some("foo &amp; bar".into()),
+        url: Some("foo?a=1&amp;b=2".into()),
+        authors: vec![Author {
+          name: "foo &amp; bar".into(),
+          url: Some("foo?a=1&amp;b=2".into()),
+        }],
       })
       .expected_metadata(Metadata {
         title: Some("foo & bar".into()),
@@ -139,6 +176,16 @@ mod tests {
         excerpt: Some("foo & bar".into()),
         site_name: Some("foo & bar".into()),
         published_time: Some("foo & bar".into()),
+        image: Some("foo.jpg?a=1&b=2".into()),
+        keywords: vec!["foo & bar".into()],
+        modified_time: Some("foo & bar".into()),
+        published_at: None,
+        section: Some("foo & bar".into()),
+        url: Some("foo?a=1&b=2".into()),
+        authors: vec![Author {
+          name: "foo & bar".into(),
+          url: Some("foo?a=1&b=2".into()),
+        }],
       })
       .run();
   }
@@ -35,6 +35,13 @@ impl UnwrapNoscriptImages {
   fn remove_placeholder_images(context: &mut Context<'_>) {
     for node in context.document.select("img").nodes().to_vec() {
       if !Self::has_image_source(&node) {
+        context.trace(
+          Some(&node),
+          TraceEventKind::Removed {
+            reason: "image without source",
+          },
+        );
+
         node.remove_from_parent();
       }
     }
@@ -0,0 +1,64 @@
+use super::*;
+
+/// Moves the contents of `Context::article` into a
+/// `<div id="readability-page-1" class="page">`, matching the shape of
+/// upstream readability's output.
+pub(crate) struct WrapPage;
+
+impl Stage for WrapPage {
+  fn run(&mut self, context: &mut Context<'_>) -> Result {
+    let Some(article) = context
+      .article
+      .and_then(|id| context.document.tree.get(&id))
+    else {
+      return Ok(());
+    };
+
+    let page = context.document.tree.new_element("div");
+
+    page.set_attr("id", "readability-page-1");
+    page.set_attr("class", "page");
+
+    if let Some(first_child) = article.first_child() {
+      page.append_children(&first_child);
+    }
+
+    article.append_child(&page);
+
+    context.trace(
+      Some(&page),
+      TraceEventKind::Message("wrapped article contents".into()),
+    );
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn wraps_article_contents() {
+    Test::new()
+      .stage(ScoreCandidates)
+      .stage(MergeSiblings)
+      .stage(WrapPage)
+      .document(&format!(
+        r"<html><body><article><p>{TEXT}</p><p>{TEXT}</p></article></body></html>"
+      ))
+      .expected_html(&format!(
+        r#"<html><head></head><body><div><div id="readability-page-1" class="page"><article><p>{TEXT}</p><p>{TEXT}</p></article></div></div></body></html>"#
+      ))
+      .run();
+  }
+
+  #[test]
+  fn without_article_leaves_document_untouched() {
+    Test::new()
+      .stage(WrapPage)
+      .document("<html><body><p>foo</p></body></html>")
+      .expected_html("<html><head></head><body><p>foo</p></body></html>")
+      .run();
+  }
+}
@@ -0,0 +1,417 @@
+use super::*;
+
+/// Month names and abbreviations in English, French, German, Spanish,
+/// Italian, Dutch and Portuguese, with their month numbers.
+const MONTHS: &[(&str, u32)] = &[
+  ("january", 1),
+  ("jan", 1),
+  ("janvier", 1),
+  ("janv", 1),
+  ("januar", 1),
+  ("jänner", 1),
+  ("enero", 1),
+  ("ene", 1),
+  ("gennaio", 1),
+  ("gen", 1),
+  ("januari", 1),
+  ("janeiro", 1),
+  ("february", 2),
+  ("feb", 2),
+  ("février", 2),
+  ("fevrier", 2),
+  ("févr", 2),
+  ("fevr", 2),
+  ("februar", 2),
+  ("febrero", 2),
+  ("febbraio", 2),
+  ("februari", 2),
+  ("fevereiro", 2),
+  ("march", 3),
+  ("mar", 3),
+  ("mars", 3),
+  ("märz", 3),
+  ("maerz", 3),
+  ("marzo", 3),
+  ("maart", 3),
+  ("março", 3),
+  ("marco", 3),
+  ("april", 4),
+  ("apr", 4),
+  ("avril", 4),
+  ("avr", 4),
+  ("abril", 4),
+  ("abr", 4),
+  ("aprile", 4),
+  ("may", 5),
+  ("mai", 5),
+  ("mayo", 5),
+  ("maggio", 5),
+  ("mag", 5),
+  ("mei", 5),
+  ("maio", 5),
+  ("june", 6),
+  ("jun", 6),
+  ("juin", 6),
+  ("juni", 6),
+  ("junio", 6),
+  ("giugno", 6),
+  ("giu", 6),
+  ("junho", 6),
+  ("july", 7),
+  ("jul", 7),
+  ("juillet", 7),
+  ("juil", 7),
+  ("juli", 7),
+  ("julio", 7),
+  ("luglio", 7),
+  ("lug", 7),
+  ("julho", 7),
+  ("august", 8),
+  ("aug", 8),
+  ("août", 8),
+  ("aout", 8),
+  ("agosto", 8),
+  ("augustus", 8),
+  ("september", 9),
+  ("sep", 9),
+  ("sept", 9),
+  ("septembre", 9),
+  ("septiembre", 9),
+  ("settembre", 9),
+  ("setembro", 9),
+  ("october", 10),
+  ("oct", 10),
+  ("octobre", 10),
+  ("oktober", 10),
+  ("okt", 10),
+  ("octubre", 10),
+  ("ottobre", 10),
+  ("ott", 10),
+  ("outubro", 10),
+  ("november", 11),
+  ("nov", 11),
+  ("novembre", 11),
+  ("noviembre", 11),
+  ("novembro", 11),
+  ("december", 12),
+  ("dec", 12),
+  ("décembre", 12),
+  ("decembre", 12),
+  ("déc", 12),
+  ("dezember", 12),
+  ("dez", 12),
+  ("diciembre", 12),
+  ("dic", 12),
+  ("dicembre", 12),
+  ("dezembro", 12),
+];
+
+/// Time zone abbreviations accepted after a date spelled with a month name,
+/// with their offsets from UTC in hours.
+const ZONES: &[(&str, i32)] = &[
+  ("z", 0),
+  ("utc", 0),
+  ("gmt", 0),
+  ("est", -5),
+  ("edt", -4),
+  ("cst", -6),
+  ("cdt", -5),
+  ("mst", -7),
+  ("mdt", -6),
+  ("pst", -8),
+  ("pdt", -7),
+  ("cet", 1),
+  ("cest", 2),
+];
+
+/// Formats with an explicit offset tried after RFC 3339.
+const OFFSET_FORMATS: &[&str] = &[
+  "%Y-%m-%dT%H:%M:%S%.f%z",
+  "%Y-%m-%dT%H:%M%z",
+  "%Y-%m-%d %H:%M:%S%.f%z",
+  "%Y-%m-%d %H:%M%z",
+];
+
+/// Formats without an offset, interpreted as UTC.
+const NAIVE_FORMATS: &[&str] = &[
+  "%Y-%m-%dT%H:%M:%S%.f",
+  "%Y-%m-%dT%H:%M",
+  "%Y-%m-%d %H:%M:%S%.f",
+  "%Y-%m-%d %H:%M",
+];
+
+/// Parses a publication date as found in metadata or markup into a
+/// timezone-aware timestamp.
+///
+/// Supports RFC 3339 and common ISO 8601 variants, RFC 2822, Unix timestamps
+/// in seconds or milliseconds, and dates spelled with a month name such as
+/// `March 3, 2021` or `3. März 2021 10:00`, optionally followed by an offset
+/// such as `+01:00`, `+0100`, `Z`, or `EST`. Values without an offset are
+/// taken to be UTC.
+pub(crate) fn parse(value: &str) -> Option<DateTime<FixedOffset>> {
+  let value = value.trim();
+
+  if value.is_empty() {
+    return None;
+  }
+
+  parse_epoch(value)
+    .or_else(|| DateTime::parse_from_rfc3339(value).ok())
+    .or_else(|| {
+      OFFSET_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(value, format).ok())
+    })
+    .or_else(|| {
+      NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+          NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|date_time| date_time.and_utc().fixed_offset())
+    })
+    .or_else(|| DateTime::parse_from_rfc2822(value).ok())
+    .or_else(|| parse_month_name(value))
+}
+
+fn parse_epoch(value: &str) -> Option<DateTime<FixedOffset>> {
+  if !value.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  let number = value.parse::<i64>().ok()?;
+
+  let date_time = match value.len() {
+    9..=11 => DateTime::from_timestamp(number, 0),
+    12..=14 => DateTime::from_timestamp_millis(number),
+    _ => None,
+  }?;
+
+  Some(date_time.fixed_offset())
+}
+
+/// Parses a date spelled with a month name.
+///
+/// When several tokens name a month, the longest wins, and the last among
+/// equally long ones, so that a leading weekday abbreviation such as the
+/// Spanish `mar.` does not shadow the month. A number directly followed by
+/// `am` or `pm` is taken as the hour rather than the day.
+fn parse_month_name(value: &str) -> Option<DateTime<FixedOffset>> {
+  let (value, offset) = split_offset(value);
+
+  let lowercase = value.to_lowercase();
+
+  let tokens = lowercase
+    .split(|c: char| !(c.is_alphanumeric() || c == ':'))
+    .filter(|token| !token.is_empty())
+    .flat_map(split_meridiem)
+    .collect::<Vec<_>>();
+
+  let month = tokens
+    .iter()
+    .filter_map(|token| MONTHS.iter().find(|(name, _)| name == token))
+    .max_by_key(|(name, _)| name.chars().count())
+    .map(|(_, month)| *month)?;
+
+  let meridiem = tokens
+    .iter()
+    .position(|token| *token == "am" || *token == "pm");
+
+  let hour = meridiem
+    .and_then(|position| position.checked_sub(1))
+    .filter(|&position| {
+      let token = tokens[position];
+      token.len() <= 2 && token.bytes().all(|byte| byte.is_ascii_digit())
+    });
+
+  let numbers = tokens
+    .iter()
+    .enumerate()
+    .filter(|&(position, token)| Some(position) != hour && !token.contains(':'))
+    .filter_map(|(_, token)| {
+      let digits = token
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(*token, |end| &token[..end]);
+
+      Some(digits).filter(|digits| !digits.is_empty())
+    })
+    .collect::<Vec<_>>();
+
+  let year = numbers
+    .iter()
+    .find(|number| number.len() == 4)?
+    .parse::<i32>()
+    .ok()?;
+
+  let day = numbers
+    .iter()
+    .find(|number| number.len() <= 2)?
+    .parse::<u32>()
+    .ok()?;
+
+  let mut time = tokens
+    .iter()
+    .find(|token| token.contains(':'))
+    .and_then(|token| {
+      NaiveTime::parse_from_str(token, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(token, "%H:%M"))
+        .ok()
+    })
+    .or_else(|| {
+      let hour = tokens[hour?].parse().ok()?;
+      NaiveTime::from_hms_opt(hour, 0, 0)
+    })
+    .unwrap_or_default();
+
+  match meridiem.map(|position| tokens[position]) {
+    Some("pm") if time.hour() < 12 => time += TimeDelta::hours(12),
+    Some("am") if time.hour() == 12 => time -= TimeDelta::hours(12),
+    _ => {}
+  }
+
+  let date_time = NaiveDate::from_ymd_opt(year, month, day)?.and_time(time);
+
+  match offset {
+    Some(offset) => date_time.and_local_timezone(offset).single(),
+    None => Some(date_time.and_utc().fixed_offset()),
+  }
+}
+
+/// Splits a token such as `4:30pm` into the time and the meridiem.
+fn split_meridiem(token: &str) -> Vec<&str> {
+  for meridiem in ["am", "pm"] {
+    if let Some(time) = token.strip_suffix(meridiem)
+      && !time.is_empty()
+      && time
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || byte == b':')
+    {
+      return vec![time, &token[time.len()..]];
+    }
+  }
+
+  vec![token]
+}
+
+/// Splits a trailing offset such as `+01:00`, `+0100`, `Z`, or `EST` from
+/// `value`.
+fn split_offset(value: &str) -> (&str, Option<FixedOffset>) {
+  let Some(captures) = TIMEZONE.captures(value) else {
+    return (value, None);
+  };
+
+  let seconds = match captures.name("zone") {
+    Some(zone) => ZONES
+      .iter()
+      .find(|(name, _)| name.eq_ignore_ascii_case(zone.as_str()))
+      .map(|(_, hours)| hours * 3600),
+    None => captures["hours"]
+      .parse::<i32>()
+      .ok()
+      .zip(captures["minutes"].parse::<i32>().ok())
+      .map(|(hours, minutes)| {
+        let seconds = hours * 3600 + minutes * 60;
+
+        if &captures["sign"] == "-" {
+          -seconds
+        } else {
+          seconds
+        }
+      }),
+  };
+
+  let start = captures
+    .name("offset")
+    .map_or(value.len(), |offset| offset.start());
+
+  match seconds.and_then(FixedOffset::east_opt) {
+    Some(offset) => (value[..start].trim_end(), Some(offset)),
+    None => (value, None),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[track_caller]
+  fn case(value: &str, expected: &str) {
+    assert_eq!(
+      parse(value),
+      Some(DateTime::parse_from_rfc3339(expected).unwrap()),
+      "{value}"
+    );
+  }
+
+  #[test]
+  fn rfc_3339() {
+    case("2021-03-03T10:00:00+01:00", "2021-03-03T10:00:00+01:00");
+    case("2021-03-03T10:00:00.123Z", "2021-03-03T10:00:00.123Z");
+  }
+
+  #[test]
+  fn iso_8601_variants() {
+    case("2021-03-03T10:00:00+0100", "2021-03-03T10:00:00+01:00");
+    case("2021-03-03 10:00:00", "2021-03-03T10:00:00Z");
+    case("2021-03-03T10:00", "2021-03-03T10:00:00Z");
+    case(" 2021-03-03 ", "2021-03-03T00:00:00Z");
+  }
+
+  #[test]
+  fn rfc_2822() {
+    case(
+      "Wed, 03 Mar 2021 10:00:00 +0100",
+      "2021-03-03T10:00:00+01:00",
+    );
+  }
+
+  #[test]
+  fn epoch() {
+    case("1614762000", "2021-03-03T09:00:00Z");
+    case("1614762000000", "2021-03-03T09:00:00Z");
+  }
+
+  #[test]
+  fn month_names() {
+    case("March 3, 2021", "2021-03-03T00:00:00Z");
+    case("Mar 3rd, 2021 at 4:30 pm", "2021-03-03T16:30:00Z");
+    case("3 March 2021", "2021-03-03T00:00:00Z");
+    case("3. März 2021, 10:00 Uhr", "2021-03-03T10:00:00Z");
+    case("le 1er mars 2021", "2021-03-01T00:00:00Z");
+    case("3 de marzo de 2021", "2021-03-03T00:00:00Z");
+    case("3 marzo 2021", "2021-03-03T00:00:00Z");
+    case("3 maart 2021", "2021-03-03T00:00:00Z");
+    case("3 de março de 2021", "2021-03-03T00:00:00Z");
+  }
+
+  #[test]
+  fn month_names_with_offset() {
+    case("March 3, 2021 10:00 +01:00", "2021-03-03T10:00:00+01:00");
+    case("March 3, 2021 10:00 +0100", "2021-03-03T10:00:00+01:00");
+    case("March 3, 2021 10:00 -05:30", "2021-03-03T10:00:00-05:30");
+    case("March 3, 2021 10:00 EST", "2021-03-03T10:00:00-05:00");
+    case("March 3, 2021 10:00 GMT", "2021-03-03T10:00:00Z");
+    case("March 3, 2021 10:00 UTC", "2021-03-03T10:00:00Z");
+    case("March 3, 2021 10:00Z", "2021-03-03T10:00:00Z");
+  }
+
+  #[test]
+  fn month_names_with_weekday_or_hour() {
+    case("mar., 5 de enero de 2021", "2021-01-05T00:00:00Z");
+    case("12 pm March 3 2021", "2021-03-03T12:00:00Z");
+    case("12 am March 3 2021", "2021-03-03T00:00:00Z");
+    case("4pm March 3 2021", "2021-03-03T16:00:00Z");
+    case("Mar 3rd, 2021 at 4:30pm", "2021-03-03T16:30:00Z");
+  }
+
+  #[test]
+  fn rejects_garbage() {
+    assert_eq!(parse(""), None);
+    assert_eq!(parse("yesterday"), None);
+    assert_eq!(parse("February 30, 2021"), None);
+    assert_eq!(parse("42"), None);
+  }
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+/// A diagnostic event emitted by a pipeline stage while
+/// `ReadabilityOptions::debug` is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+  /// What happened.
+  pub kind: TraceEventKind,
+  /// Path to the node the event concerns, such as
+  /// `html > body > div#main > p.intro`.
+  pub path: Option<String>,
+  /// Name of the stage that emitted the event.
+  pub stage: &'static str,
+}
+
+impl Display for TraceEvent {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "[{}]", self.stage)?;
+
+    if let Some(path) = &self.path {
+      write!(f, " {path}:")?;
+    }
+
+    match &self.kind {
+      TraceEventKind::Message(message) => write!(f, " {message}"),
+      TraceEventKind::Removed { reason } => write!(f, " removed ({reason})"),
+      TraceEventKind::Score { from, to } => {
+        write!(f, " score {from:.3} -> {to:.3}")
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceEventKind {
+  /// A decision that neither removed a node nor changed a score.
+  Message(Cow<'static, str>),
+  /// The node was removed from the document.
+  Removed { reason: &'static str },
+  /// The node's content score changed.
+  Score { from: f64, to: f64 },
+}
+
+/// Destination for `TraceEvent`s.
+pub trait TraceSink: Debug + Send + Sync {
+  fn record(&self, event: TraceEvent);
+}
+
+/// Writes every event to stderr, one per line.
+#[derive(Debug, Default)]
+pub struct StderrSink;
+
+impl TraceSink for StderrSink {
+  fn record(&self, event: TraceEvent) {
+    eprintln!("{event}");
+  }
+}
+
+/// Collects events in memory. Clones share the same buffer, so a clone can be
+/// handed to `ReadabilityOptionsBuilder::trace_sink` and inspected afterwards.
+#[derive(Debug, Default, Clone)]
+pub struct VecSink {
+  events: Arc<Mutex<Vec<TraceEvent>>>,
+}
+
+impl TraceSink for VecSink {
+  fn record(&self, event: TraceEvent) {
+    self
+      .events
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .push(event);
+  }
+}
+
+impl VecSink {
+  /// Returns the events recorded so far.
+  #[must_use]
+  pub fn events(&self) -> Vec<TraceEvent> {
+    self
+      .events
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .clone()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      TraceEvent {
+        kind: TraceEventKind::Removed { reason: "hidden" },
+        path: Some("html > body > p".into()),
+        stage: "RemoveHiddenNodes",
+      }
+      .to_string(),
+      "[RemoveHiddenNodes] html > body > p: removed (hidden)"
+    );
+
+    assert_eq!(
+      TraceEvent {
+        kind: TraceEventKind::Score { from: 1.0, to: 0.5 },
+        path: None,
+        stage: "ScoreCandidates",
+      }
+      .to_string(),
+      "[ScoreCandidates] score 1.000 -> 0.500"
+    );
+  }
+
+  #[test]
+  fn vec_sink_clones_share_events() {
+    let sink = VecSink::default();
+
+    sink.clone().record(TraceEvent {
+      kind: TraceEventKind::Message("foo".into()),
+      path: None,
+      stage: "Test",
+    });
+
+    assert_eq!(sink.events().len(), 1);
+  }
+}
@@ -3,7 +3,9 @@
 use {
   assert_html_eq::assert_html_eq,
   pretty_assertions::assert_eq,
-  readability::{Readability, ReadabilityOptions},
+  readability::{
+    Readability, ReadabilityOptions, ReaderableOptions, is_probably_readerable,
+  },
   serde::{Deserialize, Serialize},
   std::{fs, path::PathBuf},
 };
@@ -126,6 +128,12 @@ impl TestFixture {
       article.published_time, self.expected_metadata.published_time,
       "Published time mismatch"
     );
+
+    assert_eq!(
+      is_probably_readerable(&self.source_html, &ReaderableOptions::default()),
+      self.expected_metadata.readerable,
+      "Readerable mismatch"
+    );
   }
 
   fn test_output(&self) {
@@ -133,6 +141,27 @@ impl TestFixture {
   }
 }
 
+#[test]
+fn test_output_page_container() {
+  let paragraph = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+  let mut readability = Readability::new(
+    &format!(
+      r#"<html><head><title>foo</title></head><body><nav><a href="/">Home</a></nav><article class="story"><h2>bar</h2><p>{paragraph}</p><p>{paragraph} <a href="baz.html">baz</a></p></article><footer>qux</footer></body></html>"#
+    ),
+    Some("http://fakehost/test/page.html"),
+    ReadabilityOptions::builder().min_text_length(0).build(),
+  )
+  .unwrap();
+
+  assert_html_eq!(
+    readability.parse().unwrap().content,
+    format!(
+      r#"<div id="readability-page-1" class="page"><article><h2>bar</h2><p>{paragraph}</p><p>{paragraph} <a href="http://fakehost/test/baz.html">baz</a></p></article></div>"#
+    )
+  );
+}
+
 test_metadata!("001");
 test_metadata!("002");
 test_metadata!("004-metadata-space-separated-properties");